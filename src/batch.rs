@@ -0,0 +1,298 @@
+//! Processing of many sudokus at once
+use crate::consts::N_CELLS;
+use crate::errors::{CountedLinesError, OpenSudokuError};
+use crate::Sudoku;
+use std::collections::HashSet;
+use std::io::{self, BufRead, Write};
+
+// Number of lines after which the output is flushed
+const FLUSH_INTERVAL: usize = 1000;
+// Number of puzzles after which progress is reported
+const PROGRESS_INTERVAL: usize = 100;
+
+/// Reads sudokus in the line format, one per line, from `input`, solves them and writes the results
+/// to `output` line by line as it goes.
+///
+/// For every input line exactly one output line is written:
+/// - the solutions found, up to `limit` of them, in line format and separated by spaces
+/// - `unsolvable`, if the sudoku has no solution
+/// - `error: ` followed by the parse error, if the line could not be parsed as a sudoku
+///
+/// Memory usage is independent of the size of the input. The output is flushed periodically
+/// and at the end. Only I/O errors abort processing.
+///
+/// # Panics
+/// Panics, if `limit` is 0.
+pub fn solve_file<R: BufRead, W: Write>(input: R, mut output: W, limit: usize) -> io::Result<()> {
+    assert!(limit > 0, "limit must be at least 1");
+    for (n_line, line) in input.lines().enumerate() {
+        let line = line?;
+        match Sudoku::from_str_line(&line) {
+            Ok(sudoku) => {
+                let solutions = sudoku.solutions_up_to(limit);
+                if solutions.is_empty() {
+                    write!(output, "unsolvable")?;
+                }
+                for (i, solution) in solutions.iter().enumerate() {
+                    if i != 0 {
+                        write!(output, " ")?;
+                    }
+                    write!(output, "{}", solution)?;
+                }
+            }
+            Err(e) => write!(output, "error: {}", e)?,
+        }
+        writeln!(output)?;
+
+        if (n_line + 1) % FLUSH_INTERVAL == 0 {
+            output.flush()?;
+        }
+    }
+    output.flush()
+}
+
+/// Solves all `puzzles` and returns up to `limit` solutions for each of them, like [`Sudoku::solutions_up_to`].
+///
+/// `on_progress` is called with the number of puzzles solved so far and the total number of puzzles
+/// after every 100 puzzles and once at the end, so it can be used to update a progress bar without slowing
+/// down the solving. It is not called, if there are no puzzles.
+///
+/// ```
+/// use sudoku::batch::solve_batch_with_progress;
+/// use sudoku::Sudoku;
+///
+/// let puzzles = vec![Sudoku::generate(); 250];
+/// let mut progress = vec![];
+/// let solutions = solve_batch_with_progress(&puzzles, 1, |done, total| progress.push((done, total)));
+///
+/// assert_eq!(solutions.len(), 250);
+/// assert_eq!(progress, [(100, 250), (200, 250), (250, 250)]);
+/// ```
+pub fn solve_batch_with_progress(
+    puzzles: &[Sudoku],
+    limit: usize,
+    mut on_progress: impl FnMut(usize, usize),
+) -> Vec<Vec<Sudoku>> {
+    let mut solutions = Vec::with_capacity(puzzles.len());
+    for (n_done, puzzle) in (1..).zip(puzzles) {
+        solutions.push(puzzle.solutions_up_to(limit));
+        if n_done % PROGRESS_INTERVAL == 0 || n_done == puzzles.len() {
+            on_progress(n_done, puzzles.len());
+        }
+    }
+    solutions
+}
+
+/// Filters `puzzles` down to the uniquely solvable ones and removes all puzzles that are
+/// equivalent to an earlier one. The order of the remaining puzzles is preserved.
+///
+/// Two puzzles are equivalent, if one can be transformed into the other by the validity preserving
+/// transformations listed in [`Sudoku::shuffle`]. Equivalence is checked via [`Sudoku::canonicalized`].
+///
+/// Every puzzle is solved and canonicalized once, so the runtime grows linearly with the number of puzzles.
+/// Canonicalization of a single puzzle is, however, considerably more expensive than solving it,
+/// because it searches through all band, stack and line permutations of its solution.
+pub fn retain_proper(puzzles: Vec<Sudoku>) -> Vec<Sudoku> {
+    let mut seen = HashSet::new();
+    puzzles
+        .into_iter()
+        .filter(|puzzle| match puzzle.canonicalized() {
+            Some((canonical, _)) => seen.insert(canonical),
+            None => false,
+        })
+        .collect()
+}
+
+/// Checks for every sudoku whether it is completely and correctly solved, like [`Sudoku::is_solved`].
+///
+/// With the `simd` feature, several sudokus are checked at once with the masks of all their rows,
+/// columns and blocks side by side, so the checks can be vectorized. The results are the same either way.
+pub fn verify_complete_batch(sudokus: &[Sudoku]) -> Vec<bool> {
+    #[cfg(feature = "simd")]
+    {
+        verify_complete_lanes(sudokus)
+    }
+    #[cfg(not(feature = "simd"))]
+    {
+        sudokus.iter().map(Sudoku::is_solved).collect()
+    }
+}
+
+#[cfg(feature = "simd")]
+fn verify_complete_lanes(sudokus: &[Sudoku]) -> Vec<bool> {
+    use crate::board::{block, col, row};
+    use crate::consts::{BLOCK_OFFSET, COL_OFFSET, N_HOUSES};
+
+    // number of sudokus checked at once
+    const LANES: usize = 8;
+    const ALL_DIGITS: u16 = 0b11_1111_1110;
+
+    let mut results = Vec::with_capacity(sudokus.len());
+    for chunk in sudokus.chunks(LANES) {
+        let mut grids = [[0u8; LANES]; N_CELLS];
+        for (lane, sudoku) in chunk.iter().enumerate() {
+            for (cell, &num) in sudoku.as_bytes().iter().enumerate() {
+                grids[cell][lane] = num;
+            }
+        }
+
+        // an empty cell sets bit 0 and thereby fails the check
+        let mut houses = [[0u16; LANES]; N_HOUSES];
+        for (cell, nums) in (0..).zip(grids.iter()) {
+            let houses_of_cell = [row(cell), COL_OFFSET + col(cell), BLOCK_OFFSET + block(cell)];
+            for &house in &houses_of_cell {
+                for (mask, &num) in houses[house as usize].iter_mut().zip(nums.iter()) {
+                    *mask |= 1 << num;
+                }
+            }
+        }
+
+        results.extend((0..chunk.len()).map(|lane| {
+            houses
+                .iter()
+                .fold(true, |is_solved, house| is_solved & (house[lane] == ALL_DIGITS))
+        }));
+    }
+    results
+}
+
+/// Summary of a collection of puzzles returned by [`classify_batch`]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BatchStats {
+    /// Number of puzzles without a solution
+    pub n_unsolvable: usize,
+    /// Number of puzzles with exactly one solution
+    pub n_unique: usize,
+    /// Number of puzzles with more than one solution
+    pub n_multiple: usize,
+    /// Average number of clues per puzzle, 0 if there are no puzzles
+    pub average_clues: f64,
+}
+
+/// Counts how many of the `puzzles` have no, one or multiple solutions and averages their number of clues.
+///
+/// Every puzzle is solved once, until a second solution is found.
+pub fn classify_batch(puzzles: &[Sudoku]) -> BatchStats {
+    let mut stats = BatchStats::default();
+    let mut n_clues = 0;
+    for puzzle in puzzles {
+        match puzzle.solutions_count_up_to(2) {
+            0 => stats.n_unsolvable += 1,
+            1 => stats.n_unique += 1,
+            _ => stats.n_multiple += 1,
+        }
+        n_clues += puzzle.n_clues() as usize;
+    }
+    if !puzzles.is_empty() {
+        stats.average_clues = n_clues as f64 / puzzles.len() as f64;
+    }
+    stats
+}
+
+/// Reads a collection of sudokus in the line format, one per line, that may start with a line
+/// containing only the number of sudokus, as in many benchmark collections.
+///
+/// The first line is taken as the count, if it consists of fewer than 81 digits. If the count is present,
+/// it must match the number of sudokus read. Without it, every line is read as a sudoku. Empty lines are skipped. Line numbers in errors start at 1.
+///
+/// ```
+/// use sudoku::batch::from_counted_lines;
+/// use sudoku::errors::CountedLinesError;
+///
+/// let line = "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...";
+/// let counted = format!("2\n{}\n{}\n", line, line);
+/// assert_eq!(from_counted_lines(&counted).unwrap().len(), 2);
+/// assert_eq!(from_counted_lines(&counted[2..]).unwrap().len(), 2);
+///
+/// let wrong_count = format!("3\n{}\n{}\n", line, line);
+/// assert_eq!(
+///     from_counted_lines(&wrong_count),
+///     Err(CountedLinesError::CountMismatch { expected: 3, found: 2 })
+/// );
+/// ```
+pub fn from_counted_lines(s: &str) -> Result<Vec<Sudoku>, CountedLinesError> {
+    let mut lines = (1..)
+        .zip(s.lines())
+        .filter(|(_, line)| !line.trim().is_empty())
+        .peekable();
+    // a sudoku line has at least 81 characters, but may consist of digits only
+    let count = lines
+        .peek()
+        .map(|(_, line)| line.trim())
+        .filter(|line| line.len() < N_CELLS && line.bytes().all(|byte| byte.is_ascii_digit()))
+        .and_then(|line| line.parse::<usize>().ok());
+    if count.is_some() {
+        lines.next();
+    }
+
+    let sudokus = lines
+        .map(|(line, content)| {
+            Sudoku::from_str_line(content).map_err(|error| CountedLinesError::InvalidLine { line, error })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match count {
+        Some(expected) if expected != sudokus.len() => Err(CountedLinesError::CountMismatch {
+            expected,
+            found: sudokus.len(),
+        }),
+        _ => Ok(sudokus),
+    }
+}
+
+/// Reads all puzzles from a collection in the XML format of the OpenSudoku app.
+///
+/// Every `<game data="..."/>` element holds one sudoku in the line format with `0` for empty cells.
+/// The input is only scanned for these elements, everything else is ignored and the XML is not validated.
+/// A malformed `game` element results in an error in its place, the remaining elements are still read.
+pub fn from_opensudoku(xml: &str) -> Vec<Result<Sudoku, OpenSudokuError>> {
+    const GAME_TAG: &str = "<game";
+
+    let mut sudokus = vec![];
+    let mut rest = xml;
+    while let Some(start) = rest.find(GAME_TAG) {
+        rest = &rest[start + GAME_TAG.len()..];
+        // some other tag that starts with `game`
+        if !rest.starts_with(|ch: char| ch.is_whitespace() || ch == '/' || ch == '>') {
+            continue;
+        }
+        let end = rest.find('>').unwrap_or(rest.len());
+        let tag = &rest[..end];
+        rest = &rest[end..];
+
+        let sudoku = attribute_value(tag, "data")
+            .ok_or(OpenSudokuError::MissingData)
+            .and_then(|data| Sudoku::from_str_line(data).map_err(OpenSudokuError::InvalidData));
+        sudokus.push(sudoku);
+    }
+    sudokus
+}
+
+// Returns the value of the attribute `name` in the contents of an XML tag
+fn attribute_value<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(start) = rest.find(name) {
+        let is_name_start = rest[..start].ends_with(char::is_whitespace);
+        rest = rest[start + name.len()..].trim_start();
+        if !is_name_start || !rest.starts_with('=') {
+            continue;
+        }
+        rest = rest[1..].trim_start();
+        let quote = rest.chars().next().filter(|&ch| ch == '"' || ch == '\'')?;
+        rest = &rest[1..];
+        return rest.find(quote).map(|end| &rest[..end]);
+    }
+    None
+}
+
+/// Writes the `sudokus` as a collection in the XML format of the OpenSudoku app.
+/// The result can be read with [`from_opensudoku`].
+pub fn to_opensudoku(sudokus: &[Sudoku]) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<opensudoku>\n");
+    for sudoku in sudokus {
+        xml.push_str("  <game data=\"");
+        xml.extend(sudoku.as_bytes().iter().map(|&num| (b'0' + num) as char));
+        xml.push_str("\" />\n");
+    }
+    xml.push_str("</opensudoku>\n");
+    xml
+}
@@ -124,6 +124,7 @@ where
     pub const NONE: Set<T> = Set(<T as SetElement>::NONE);
 
     /// Construct a bitset from a raw integer.
+    /// See [`Set::bits`] for the layout.
     ///
     /// # Panic
     /// Panics, if the integer contains bits above [`Set::ALL`]
@@ -132,7 +133,23 @@ where
         Set(mask)
     }
 
+    /// Construct a bitset from a raw integer.
+    /// See [`Set::bits`] for the layout.
+    ///
+    /// Returns `None`, if the integer contains bits above [`Set::ALL`].
+    pub fn try_from_bits(mask: T::Storage) -> Option<Self> {
+        match mask <= <T as SetElement>::ALL {
+            true => Some(Set(mask)),
+            false => None,
+        }
+    }
+
     /// Return the raw integer backing the set.
+    ///
+    /// Bit `n` is set, iff the element with index `n` is contained, where the index is the one returned by
+    /// the element's `as_index` method. For a `Set<Digit>` that means bit `d - 1` stands for digit `d`, so
+    /// only the lowest 9 bits of the `u16` can be set. This layout is stable and can be relied upon,
+    /// e.g. for passing candidates over an FFI boundary.
     pub fn bits(self) -> T::Storage {
         self.0
     }
@@ -2,17 +2,31 @@
 mod candidate;
 mod canonicalization;
 mod cell_state;
+mod code;
+mod csv;
 mod digit;
+mod editor;
 mod grid_state;
+mod json;
+mod pencil_marks;
 pub mod positions;
+mod puzzle;
+mod reader;
 mod sudoku;
+mod transform;
 
 pub(crate) use self::positions::*;
 
 #[rustfmt::skip]
 pub use self::{
     sudoku::Sudoku,
+    sudoku::AnswerResult,
+    sudoku::SudokuLine,
     sudoku::Symmetry,
+    transform::Transform,
+    puzzle::Puzzle,
+    editor::Editor,
+    pencil_marks::PencilMarks,
     digit::Digit,
     positions::Cell,
     candidate::Candidate,
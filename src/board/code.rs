@@ -0,0 +1,65 @@
+//! URL-safe base64 encoding of the packed sudoku representation, without padding.
+use crate::errors::FromCodeError;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const PACKED_LEN: usize = 41;
+// 13 groups of 3 bytes in 4 chars each and the remaining 2 bytes in 3 chars
+const CODE_LEN: usize = 55;
+
+pub(crate) fn encode(packed: &[u8; PACKED_LEN]) -> String {
+    let mut code = String::with_capacity(CODE_LEN);
+    for group in packed.chunks(3) {
+        let bits = group
+            .iter()
+            .enumerate()
+            .fold(0u32, |bits, (i, &byte)| bits | (byte as u32) << (16 - 8 * i));
+        // 1 more char than bytes in the group
+        for i in 0..=group.len() {
+            code.push(ALPHABET[(bits >> (18 - 6 * i) & 0x3F) as usize] as char);
+        }
+    }
+    code
+}
+
+pub(crate) fn decode(code: &str) -> Result<[u8; PACKED_LEN], FromCodeError> {
+    let invalid_char = code
+        .char_indices()
+        .find(|&(_, ch)| !ch.is_ascii() || sextet(ch as u8).is_none());
+    if let Some((pos, ch)) = invalid_char {
+        return Err(FromCodeError::InvalidChar { pos, ch });
+    }
+    if code.len() != CODE_LEN {
+        return Err(FromCodeError::WrongLength(code.len()));
+    }
+    let mut packed = [0; PACKED_LEN];
+    for (n_group, (group, chars)) in packed.chunks_mut(3).zip(code.as_bytes().chunks(4)).enumerate() {
+        let mut bits = 0u32;
+        for (i, &ch) in chars.iter().enumerate() {
+            bits |= sextet(ch).unwrap() << (18 - 6 * i);
+        }
+        // unused low bits of the last char must be 0, so that every sudoku has exactly one code
+        if bits & !(u32::MAX << (24 - 8 * group.len())) != 0 {
+            let pos = n_group * 4 + group.len();
+            return Err(FromCodeError::InvalidChar {
+                pos,
+                ch: chars[group.len()] as char,
+            });
+        }
+        for (i, byte) in group.iter_mut().enumerate() {
+            *byte = (bits >> (16 - 8 * i)) as u8;
+        }
+    }
+    Ok(packed)
+}
+
+fn sextet(ch: u8) -> Option<u32> {
+    let value = match ch {
+        b'A'..=b'Z' => ch - b'A',
+        b'a'..=b'z' => ch - b'a' + 26,
+        b'0'..=b'9' => ch - b'0' + 52,
+        b'-' => 62,
+        b'_' => 63,
+        _ => return None,
+    };
+    Some(value as u32)
+}
@@ -0,0 +1,57 @@
+//! Reader and writer for sudokus as comma-separated values, one line per row, as exported by spreadsheets.
+use crate::consts::N_CELLS;
+use crate::errors::CsvParseError;
+
+pub(crate) fn to_csv(sudoku: &[u8; N_CELLS]) -> String {
+    // up to 9 digits and 8 commas per row plus newline
+    let mut csv = String::with_capacity(9 * 18);
+    for row in sudoku.chunks(9) {
+        for (n_col, &num) in row.iter().enumerate() {
+            if n_col != 0 {
+                csv.push(',');
+            }
+            if num != 0 {
+                csv.push((b'0' + num) as char);
+            }
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+pub(crate) fn from_csv(s: &str) -> Result<[u8; N_CELLS], CsvParseError> {
+    let mut grid = [0; N_CELLS];
+    let mut n_rows = 0;
+    for line in s.lines().filter(|line| !line.trim().is_empty()) {
+        if n_rows == 9 {
+            return Err(CsvParseError::WrongNumberOfRows(10));
+        }
+        let n_fields = line.split(',').count();
+        if n_fields != 9 {
+            return Err(CsvParseError::WrongRowLength {
+                row: n_rows,
+                len: std::cmp::min(n_fields, 10) as u8,
+            });
+        }
+        for (n_col, field) in line.split(',').enumerate() {
+            let cell = n_rows * 9 + n_col as u8;
+            grid[cell as usize] = match field.trim() {
+                "" => 0,
+                field => match field.parse() {
+                    Ok(num @ 0..=9) => num,
+                    _ => {
+                        return Err(CsvParseError::InvalidEntry {
+                            cell,
+                            field: field.to_string(),
+                        })
+                    }
+                },
+            };
+        }
+        n_rows += 1;
+    }
+    if n_rows != 9 {
+        return Err(CsvParseError::WrongNumberOfRows(n_rows));
+    }
+    Ok(grid)
+}
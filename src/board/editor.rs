@@ -0,0 +1,214 @@
+use crate::bitset::Set;
+use crate::board::{Cell, Digit, House};
+use crate::consts::{N_CELLS, N_HOUSES};
+use crate::helper::{CellArray, HouseArray};
+use crate::Sudoku;
+
+/// A sudoku grid for frequent editing, which keeps the candidates of every cell up to date.
+///
+/// Entering or removing a digit only updates the cells in the same row, column and block,
+/// so the candidates don't have to be computed from scratch after every edit as with
+/// [`Sudoku::candidate_counts`]. The candidates of an empty cell are the digits that don't conflict
+/// with a filled cell in its row, column or block, filled cells have no candidates.
+/// Conflicting entries are allowed.
+///
+/// Whether the grid can still be solved is checked with [`place`](Editor::place) and
+/// [`is_solvable`](Editor::is_solvable). The last solution found is remembered, so that placements
+/// which agree with it don't require solving again.
+///
+/// ```
+/// use sudoku::{board::Editor, Sudoku};
+///
+/// let mut editor = Editor::new(Sudoku::from_bytes([0; 81]).unwrap());
+/// editor.set(0, 5);
+/// assert!(!editor.candidates(1).contains(sudoku::board::Digit::new(5)));
+///
+/// editor.clear(0);
+/// assert_eq!(editor.candidates(1).len(), 9);
+/// assert!(editor.sudoku().is_empty());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Editor {
+    grid: Sudoku,
+    // number of cells containing each digit per house
+    digit_counts: HouseArray<[u8; 9]>,
+    candidates: CellArray<Set<Digit>>,
+    // a solution that agrees with all entries, if one is known
+    solution: Option<Sudoku>,
+}
+
+impl Editor {
+    /// Starts editing `sudoku`.
+    pub fn new(sudoku: Sudoku) -> Self {
+        let mut editor = Editor {
+            grid: Sudoku([0; N_CELLS]),
+            digit_counts: HouseArray([[0; 9]; N_HOUSES]),
+            candidates: CellArray([Set::ALL; N_CELLS]),
+            solution: None,
+        };
+        for (cell, num) in (0..).zip(sudoku.iter()) {
+            if let Some(num) = num {
+                editor.set(cell, num);
+            }
+        }
+        editor
+    }
+
+    /// Enters `digit` into `cell`, replacing the previous entry if any.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81` or `digit` is not in `1..=9`.
+    pub fn set(&mut self, cell: u8, digit: u8) {
+        assert!((1..=9).contains(&digit), "digit must be in range 1..=9");
+        self.clear(cell);
+        let digit = Digit::new(digit);
+        let cell = Cell::new(cell);
+
+        self.grid.0[cell.as_index()] = digit.get();
+        if let Some(solution) = self.solution {
+            if solution.0[cell.as_index()] != digit.get() {
+                self.solution = None;
+            }
+        }
+        self.candidates[cell] = Set::NONE;
+        for &house in &cell.houses() {
+            self.digit_counts[house][digit.as_index()] += 1;
+        }
+        for peer in cell.neighbors() {
+            self.candidates[peer].remove(digit.as_set());
+        }
+    }
+
+    /// Removes the entry of `cell`, if any.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn clear(&mut self, cell: u8) {
+        assert!((cell as usize) < N_CELLS, "cell index out of range: {}", cell);
+        let digit = match Digit::new_checked(self.grid.0[cell as usize]) {
+            Some(digit) => digit,
+            None => return,
+        };
+        let cell = Cell::new(cell);
+
+        self.grid.0[cell.as_index()] = 0;
+        for &house in &cell.houses() {
+            self.digit_counts[house][digit.as_index()] -= 1;
+        }
+        self.candidates[cell] = self.compute_candidates(cell);
+        // peers regain the digit, unless it's still blocked by another entry
+        for peer in cell.neighbors() {
+            if self.grid.0[peer.as_index()] == 0 && !self.is_blocked(peer, digit) {
+                self.candidates[peer] |= digit.as_set();
+            }
+        }
+    }
+
+    /// Enters `digit` into `cell` like [`set`](Editor::set) and returns whether the grid can still be solved.
+    ///
+    /// If the remembered solution contains `digit` in `cell`, it is still a solution and no solving
+    /// is necessary. Otherwise, the grid is first checked for contradictions around `cell` using the
+    /// candidates and only then solved.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81` or `digit` is not in `1..=9`.
+    pub fn place(&mut self, cell: u8, digit: u8) -> bool {
+        self.set(cell, digit);
+        if self.solution.is_some() {
+            return true;
+        }
+        if self.has_local_contradiction(Cell::new(cell), Digit::new(digit)) {
+            return false;
+        }
+        self.is_solvable()
+    }
+
+    /// Returns whether the grid can be solved, solving it if no solution is remembered.
+    pub fn is_solvable(&mut self) -> bool {
+        if self.solution.is_none() {
+            self.solution = self.grid.some_solution();
+        }
+        self.solution.is_some()
+    }
+
+    // Checks the houses affected by entering `digit` into `cell` for conflicts, cells without candidates
+    // and digits without a place. Only the cell and its neighbors have lost candidates.
+    fn has_local_contradiction(&self, cell: Cell, digit: Digit) -> bool {
+        let has_conflict = cell
+            .houses()
+            .iter()
+            .any(|&house| self.digit_counts[house][digit.as_index()] > 1);
+        let has_empty_neighbor = cell
+            .neighbors()
+            .into_iter()
+            .any(|peer| self.grid.0[peer.as_index()] == 0 && self.candidates[peer].is_empty());
+        has_conflict || has_empty_neighbor || self.has_digit_without_place(cell, digit)
+    }
+
+    fn has_digit_without_place(&self, cell: Cell, digit: Digit) -> bool {
+        // the houses of `cell` lost all other digits in `cell`, the houses of its neighbors lost `digit`
+        let missing_place = |house: House, digit: Digit| {
+            self.digit_counts[house][digit.as_index()] == 0
+                && house
+                    .cells()
+                    .into_iter()
+                    .all(|cell| !self.candidates[cell].contains(digit))
+        };
+        let own = cell.houses().iter().any(|&house| {
+            Set::<Digit>::ALL
+                .into_iter()
+                .any(|digit| missing_place(house, digit))
+        });
+        let neighbors = cell
+            .neighbors()
+            .into_iter()
+            .any(|peer| peer.houses().iter().any(|&house| missing_place(house, digit)));
+        own || neighbors
+    }
+
+    fn is_blocked(&self, cell: Cell, digit: Digit) -> bool {
+        cell.houses()
+            .iter()
+            .any(|&house| self.digit_counts[house][digit.as_index()] != 0)
+    }
+
+    fn compute_candidates(&self, cell: Cell) -> Set<Digit> {
+        Set::<Digit>::ALL
+            .into_iter()
+            .filter(|&digit| !self.is_blocked(cell, digit))
+            .fold(Set::NONE, |candidates, digit| candidates | digit)
+    }
+
+    /// Returns the entry of `cell`, if any.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn get(&self, cell: u8) -> Option<u8> {
+        Digit::new_checked(self.grid.0[cell as usize]).map(Digit::get)
+    }
+
+    /// Returns the candidates of `cell`. Filled cells have no candidates.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn candidates(&self, cell: u8) -> Set<Digit> {
+        self.candidates.0[cell as usize]
+    }
+
+    /// Returns the current grid.
+    pub fn sudoku(&self) -> Sudoku {
+        self.grid
+    }
+}
+
+impl From<Sudoku> for Editor {
+    fn from(sudoku: Sudoku) -> Self {
+        Editor::new(sudoku)
+    }
+}
+
+impl From<Editor> for Sudoku {
+    fn from(editor: Editor) -> Self {
+        editor.sudoku()
+    }
+}
@@ -0,0 +1,136 @@
+//! Minimal reader and writer for sudokus as nested JSON arrays, e.g. `[[0,3,0,...],[...],...]`.
+//! Only the subset of JSON needed for this is supported: arrays, non-negative integers and whitespace.
+use crate::consts::N_CELLS;
+use crate::errors::JsonParseError;
+use std::{iter::Peekable, str::CharIndices};
+
+pub(crate) fn to_json_array(sudoku: &[u8; N_CELLS]) -> String {
+    // 9 rows of 9 single digit entries with commas and brackets
+    let mut json = String::with_capacity(2 + 9 * 19 + 8);
+    json.push('[');
+    for (n_row, row) in sudoku.chunks(9).enumerate() {
+        if n_row != 0 {
+            json.push(',');
+        }
+        json.push('[');
+        for (n_col, &num) in row.iter().enumerate() {
+            if n_col != 0 {
+                json.push(',');
+            }
+            json.push((b'0' + num) as char);
+        }
+        json.push(']');
+    }
+    json.push(']');
+    json
+}
+
+pub(crate) fn from_json_array(s: &str) -> Result<[u8; N_CELLS], JsonParseError> {
+    let mut parser = Parser {
+        chars: s.char_indices().peekable(),
+    };
+    let mut grid = [0; N_CELLS];
+
+    parser.expect('[')?;
+    let mut n_rows = 0;
+    loop {
+        if n_rows == 9 {
+            return Err(JsonParseError::WrongNumberOfRows(10));
+        }
+        parser.expect('[')?;
+        let mut n_cols = 0;
+        loop {
+            let (pos, value) = parser.number()?;
+            if n_cols == 9 {
+                return Err(JsonParseError::WrongRowLength { row: n_rows, len: 10 });
+            }
+            if value > 9 {
+                return Err(JsonParseError::InvalidEntry { pos, value });
+            }
+            grid[n_rows as usize * 9 + n_cols as usize] = value as u8;
+            n_cols += 1;
+            if !parser.comma_or_end()? {
+                break;
+            }
+        }
+        if n_cols != 9 {
+            return Err(JsonParseError::WrongRowLength {
+                row: n_rows,
+                len: n_cols,
+            });
+        }
+        n_rows += 1;
+        if !parser.comma_or_end()? {
+            break;
+        }
+    }
+    if n_rows != 9 {
+        return Err(JsonParseError::WrongNumberOfRows(n_rows));
+    }
+    parser.end()?;
+
+    Ok(grid)
+}
+
+struct Parser<'a> {
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while let Some(&(_, ch)) = self.chars.peek() {
+            match ch {
+                ' ' | '\t' | '\n' | '\r' => {
+                    self.chars.next();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn next_significant(&mut self) -> Result<(usize, char), JsonParseError> {
+        self.skip_whitespace();
+        self.chars.next().ok_or(JsonParseError::UnexpectedEnd)
+    }
+
+    fn unexpected((pos, ch): (usize, char)) -> JsonParseError {
+        JsonParseError::UnexpectedChar { pos, ch }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonParseError> {
+        match self.next_significant()? {
+            (_, ch) if ch == expected => Ok(()),
+            other => Err(Self::unexpected(other)),
+        }
+    }
+
+    // Consumes the separator after an array element.
+    // Returns `true` if another element follows and `false` at the end of the array.
+    fn comma_or_end(&mut self) -> Result<bool, JsonParseError> {
+        match self.next_significant()? {
+            (_, ',') => Ok(true),
+            (_, ']') => Ok(false),
+            other => Err(Self::unexpected(other)),
+        }
+    }
+
+    // Parses a non-negative integer and returns it together with its byte position.
+    // Values too large for a u32 saturate, they are rejected by the caller anyway.
+    fn number(&mut self) -> Result<(usize, u32), JsonParseError> {
+        let (pos, first) = self.next_significant()?;
+        let mut value = first.to_digit(10).ok_or_else(|| Self::unexpected((pos, first)))?;
+        while let Some(digit) = self.chars.peek().and_then(|&(_, ch)| ch.to_digit(10)) {
+            value = value.saturating_mul(10).saturating_add(digit);
+            self.chars.next();
+        }
+        Ok((pos, value))
+    }
+
+    fn end(&mut self) -> Result<(), JsonParseError> {
+        self.skip_whitespace();
+        match self.chars.next() {
+            None => Ok(()),
+            Some(other) => Err(Self::unexpected(other)),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::bitset::Set;
+use crate::board::Digit;
+use crate::consts::N_CELLS;
+use crate::Sudoku;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// The candidates a player has noted down in each cell, independently of the grid.
+///
+/// Unlike the candidates computed by [`Sudoku::candidate_counts`] or the [`Editor`](crate::board::Editor),
+/// pencil marks are only changed by the player. They can be [filled in](PencilMarks::from_candidates)
+/// from the computed candidates once and edited from there on.
+///
+/// The marks of each cell can be stored as a bitmask in the layout of [`Set::bits`], see
+/// [`to_bits`](PencilMarks::to_bits). With the `serde` feature, they are serialized as a sequence of
+/// the 81 bitmasks.
+///
+/// ```
+/// use sudoku::board::PencilMarks;
+///
+/// let mut marks = PencilMarks::new();
+/// marks.toggle(0, 5);
+/// marks.insert(0, 7);
+/// assert!(marks.contains(0, 5));
+///
+/// marks.toggle(0, 5);
+/// assert!(!marks.contains(0, 5));
+/// assert_eq!(marks.to_bits()[0], 1 << 6);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct PencilMarks {
+    marks: [Set<Digit>; N_CELLS],
+}
+
+impl PencilMarks {
+    /// Creates pencil marks without any marks.
+    pub fn new() -> Self {
+        PencilMarks {
+            marks: [Set::NONE; N_CELLS],
+        }
+    }
+
+    /// Marks every candidate of the empty cells of `sudoku`, i.e. the digits that don't conflict with
+    /// a filled cell in the same row, column or block. Filled cells get no marks.
+    pub fn from_candidates(sudoku: &Sudoku) -> Self {
+        PencilMarks {
+            marks: sudoku.cell_candidates(),
+        }
+    }
+
+    /// Creates pencil marks from one bitmask per cell in the layout of [`to_bits`](PencilMarks::to_bits).
+    ///
+    /// Returns `None`, if a bitmask has bits set above the 9 lowest ones.
+    pub fn from_bits(bits: &[u16; N_CELLS]) -> Option<Self> {
+        let mut marks = PencilMarks::new();
+        for (cell_marks, &bits) in marks.marks.iter_mut().zip(bits.iter()) {
+            *cell_marks = Set::try_from_bits(bits)?;
+        }
+        Some(marks)
+    }
+
+    /// Returns the marks of each cell as a bitmask, where bit `d - 1` stands for digit `d`.
+    /// See [`Set::bits`].
+    pub fn to_bits(&self) -> [u16; N_CELLS] {
+        let mut bits = [0; N_CELLS];
+        for (bits, cell_marks) in bits.iter_mut().zip(self.marks.iter()) {
+            *bits = cell_marks.bits();
+        }
+        bits
+    }
+
+    /// Marks `digit` in `cell`.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81` or `digit` is not in `1..=9`.
+    pub fn insert(&mut self, cell: u8, digit: u8) {
+        let digit = check_digit(digit);
+        self.marks[cell as usize] |= digit;
+    }
+
+    /// Removes the mark of `digit` in `cell`, if any.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81` or `digit` is not in `1..=9`.
+    pub fn remove(&mut self, cell: u8, digit: u8) {
+        let digit = check_digit(digit);
+        self.marks[cell as usize].remove(digit.as_set());
+    }
+
+    /// Marks `digit` in `cell`, if it isn't marked, and removes the mark otherwise.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81` or `digit` is not in `1..=9`.
+    pub fn toggle(&mut self, cell: u8, digit: u8) {
+        let digit = check_digit(digit);
+        self.marks[cell as usize] ^= digit.as_set();
+    }
+
+    /// Checks whether `digit` is marked in `cell`.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81` or `digit` is not in `1..=9`.
+    pub fn contains(&self, cell: u8, digit: u8) -> bool {
+        let digit = check_digit(digit);
+        self.marks[cell as usize].contains(digit)
+    }
+
+    /// Replaces the marks of `cell` with `digits`.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn set(&mut self, cell: u8, digits: Set<Digit>) {
+        self.marks[cell as usize] = digits;
+    }
+
+    /// Returns the marks of `cell`.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn get(&self, cell: u8) -> Set<Digit> {
+        self.marks[cell as usize]
+    }
+
+    /// Removes all marks of `cell`.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn clear(&mut self, cell: u8) {
+        self.marks[cell as usize] = Set::NONE;
+    }
+
+    /// Checks whether no cell has any marks.
+    pub fn is_empty(&self) -> bool {
+        self.marks.iter().all(|cell_marks| cell_marks.is_empty())
+    }
+}
+
+fn check_digit(digit: u8) -> Digit {
+    assert!((1..=9).contains(&digit), "digit must be in range 1..=9");
+    Digit::new(digit)
+}
+
+impl Default for PencilMarks {
+    fn default() -> Self {
+        PencilMarks::new()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for PencilMarks {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_bits()[..].serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for PencilMarks {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use std::convert::TryFrom;
+
+        let bits = Vec::<u16>::deserialize(deserializer)?;
+        let bits = <&[u16; N_CELLS]>::try_from(&bits[..])
+            .map_err(|_| de::Error::invalid_length(bits.len(), &"81 bitmasks"))?;
+        PencilMarks::from_bits(bits).ok_or_else(|| de::Error::custom("bitmask contains bits above digit 9"))
+    }
+}
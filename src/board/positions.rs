@@ -9,25 +9,125 @@ use crate::consts::*;
 use crate::helper::Unsolvable;
 use std::num::NonZeroU8;
 
+/// Returns the row index (`0..9`) of `cell`, topmost row is 0.
+///
+/// # Panics
+/// Panics, if `cell` is outside of `0..81`.
 #[inline(always)]
-pub(crate) fn row(cell: u8) -> u8 {
+pub fn row(cell: u8) -> u8 {
+    assert!(cell < 81, "cell index out of range: {}", cell);
+    _row(cell)
+}
+
+/// Returns the column index (`0..9`) of `cell`, leftmost column is 0.
+///
+/// # Panics
+/// Panics, if `cell` is outside of `0..81`.
+#[inline(always)]
+pub fn col(cell: u8) -> u8 {
+    assert!(cell < 81, "cell index out of range: {}", cell);
+    _col(cell)
+}
+
+// Unchecked versions of `row` and `col` for cells that are known to be valid
+#[inline(always)]
+pub(crate) fn _row(cell: u8) -> u8 {
+    debug_assert!(cell < 81);
     cell / 9
 }
+
 #[inline(always)]
-pub(crate) fn col(cell: u8) -> u8 {
+pub(crate) fn _col(cell: u8) -> u8 {
+    debug_assert!(cell < 81);
     cell % 9
 }
+
+/// Returns the row and column index of `cell`, see [`row`] and [`col`].
+///
+/// # Panics
+/// Panics, if `cell` is outside of `0..81`.
+#[inline(always)]
+pub fn row_col(cell: u8) -> (u8, u8) {
+    assert!(cell < 81, "cell index out of range: {}", cell);
+    (cell / 9, cell % 9)
+}
+
+/// Returns the row, column and block index of `cell`, see [`row`], [`col`] and [`block`].
+///
+/// # Panics
+/// Panics, if `cell` is outside of `0..81`.
+#[inline(always)]
+pub fn row_col_block(cell: u8) -> (u8, u8, u8) {
+    let (row, col) = row_col(cell);
+    (row, col, block(cell))
+}
+
+/// Returns the cell at the intersection of `row` and `col`, numbering from left to right, top to bottom.
+/// This is the inverse of [`row_col`].
+///
+/// # Panics
+/// Panics, if `row` or `col` is outside of `0..9`.
+#[inline(always)]
+pub fn cell_index(row: u8, col: u8) -> u8 {
+    assert!(
+        row < 9 && col < 9,
+        "cell coordinates out of range: ({}, {})",
+        row,
+        col
+    );
+    row * 9 + col
+}
+
+/// Returns the block index (`0..9`) of `cell`, numbering from left to right, top to bottom.
+///
+/// # Panics
+/// Panics, if `cell` is outside of `0..81`.
 #[inline(always)]
-pub(crate) fn block(cell: u8) -> u8 {
+pub fn block(cell: u8) -> u8 {
     BLOCK[cell as usize]
 }
 
+/// Returns the 20 cells that share a row, column or block with `cell`, sorted from low to high.
+///
+/// # Panics
+/// Panics, if `cell` is outside of `0..81`.
+pub fn neighbors(cell: u8) -> &'static [u8] {
+    &HOUSE_NEIGHBORS_OF_CELL[cell as usize]
+}
+
+/// Returns the 9 cells of `row`, from left to right.
+///
+/// # Panics
+/// Panics, if `row` is outside of `0..9`.
+pub fn cells_in_row(row: u8) -> &'static [u8; 9] {
+    assert!(row < 9);
+    &CELLS_BY_HOUSE[row as usize]
+}
+
+/// Returns the 9 cells of `col`, from top to bottom.
+///
+/// # Panics
+/// Panics, if `col` is outside of `0..9`.
+pub fn cells_in_col(col: u8) -> &'static [u8; 9] {
+    assert!(col < 9);
+    &CELLS_BY_HOUSE[(COL_OFFSET + col) as usize]
+}
+
+/// Returns the 9 cells of `block`, from left to right, top to bottom.
+///
+/// # Panics
+/// Panics, if `block` is outside of `0..9`.
+pub fn cells_in_block(block: u8) -> &'static [u8; 9] {
+    assert!(block < 9);
+    &CELLS_BY_HOUSE[(BLOCK_OFFSET + block) as usize]
+}
+
 fn band(cell: u8) -> u8 {
     cell / 27
 }
 
 fn stack(cell: u8) -> u8 {
-    col(cell) / 3
+    _col(cell) / 3
 }
 
 #[rustfmt::skip]
@@ -324,6 +424,21 @@ pub enum ChuteType {
     Stack(Stack),
 }
 
+/// A [`Row`], [`Col`], [`Block`], [`Band`] or [`Stack`]. Returned by [`Sudoku::has_empty_unit`](crate::Sudoku::has_empty_unit).
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum Zone {
+    /// A row
+    Row(Row),
+    /// A column
+    Col(Col),
+    /// A block
+    Block(Block),
+    /// 3 rows of blocks
+    Band(Band),
+    /// 3 columns of blocks
+    Stack(Stack),
+}
+
 impl Chute {
     /// Determine whether this chute is a [`Band`] or [`Stack`]
     pub fn categorize(self) -> ChuteType {
@@ -690,21 +805,21 @@ impl_from!(
 // the first type is the container of the second
 #[rustfmt::skip]
 impl_from!(
-    Cell, Row, |c| { row(c) },
-    Cell, Col, |c| { col(c) },
+    Cell, Row, |c| { _row(c) },
+    Cell, Col, |c| { _col(c) },
     Cell, Block, |c| { 3 * band(c) + stack(c) },
     Cell, Band, |c| { band(c) },
     Cell, Stack, |c| { stack(c) },
-    Cell, Position<Row>, |c| { col(c) },
-    Cell, Position<Col>, |c| { row(c) },
+    Cell, Position<Row>, |c| { _col(c) },
+    Cell, Position<Col>, |c| { _row(c) },
     Position<Row>, Position<Line>, |pos| { pos },
     Position<Col>, Position<Line>, |pos| { pos },
     Position<Row>, Position<House>, |pos| { pos },
     Position<Col>, Position<House>, |pos| { pos },
     Position<Line>, Position<House>, |pos| { pos },
     Position<Block>, Position<House>, |pos| { pos },
-    Cell, Position<Block>, |c| { row(c) % 3 * 3 + col(c) % 3 },
-    Cell, Position<Band>, |c| { row(c) % 3 * 9 + col(c) },
+    Cell, Position<Block>, |c| { _row(c) % 3 * 3 + _col(c) % 3 },
+    Cell, Position<Band>, |c| { _row(c) % 3 * 9 + _col(c) },
     Position<Chute>, Position<Band>, |pos| { pos },
     //Cell, Position<Stack>, |c| { row(c) % 3 * 3 + col(c) % 3 }, // not sure how to lay this out, yet
 );
@@ -854,6 +969,41 @@ mod test {
         }
     }
 
+    #[test]
+    fn raw_cell_helpers() {
+        for cell in Cell::all() {
+            let raw = cell.get();
+            assert_eq!(row(raw), cell.row().get());
+            assert_eq!(col(raw), cell.col().get());
+            assert_eq!(block(raw), cell.block().get());
+            assert_eq!(row_col(raw), (row(raw), col(raw)));
+            assert_eq!(row_col_block(raw), (row(raw), col(raw), block(raw)));
+            assert_eq!(cell_index(row(raw), col(raw)), raw);
+
+            assert!(cells_in_row(row(raw)).contains(&raw));
+            assert!(cells_in_col(col(raw)).contains(&raw));
+            assert!(cells_in_block(block(raw)).contains(&raw));
+
+            let neighbors_set = cell.neighbors_set();
+            assert_eq!(neighbors(raw).len(), 20);
+            assert!(neighbors(raw)
+                .iter()
+                .all(|&n| neighbors_set.contains(Cell::new(n))));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn row_out_of_range() {
+        row(81);
+    }
+
+    #[test]
+    #[should_panic]
+    fn col_out_of_range() {
+        col(200);
+    }
+
     /*
     #[test]
     fn block_cells() {
@@ -0,0 +1,198 @@
+use crate::consts::N_CELLS;
+use crate::errors::{EditError, PuzzleParseError};
+use crate::Sudoku;
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// A sudoku being played, which distinguishes the givens of the puzzle from the digits filled in
+/// by the player.
+///
+/// The givens can't be changed, all other cells can be [set](Puzzle::set) and [cleared](Puzzle::clear)
+/// freely. Entries that conflict with other entries are allowed, so that mistakes can be
+/// [reported](Puzzle::conflicts) instead of rejected.
+///
+/// A puzzle is written as the givens and the fills in line format, separated by a space.
+/// With the `serde` feature, it is serialized as the tuple `(givens, fills)`.
+///
+/// ```
+/// use sudoku::{board::Puzzle, Sudoku};
+///
+/// let sudoku = Sudoku::from_str_line(
+///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+/// )
+/// .unwrap();
+/// let mut puzzle = Puzzle::new(sudoku);
+/// puzzle.set(0, 1).unwrap();
+/// assert!(puzzle.set(3, 1).is_err());
+///
+/// let saved = puzzle.to_string();
+/// assert_eq!(Puzzle::from_str_line(&saved), Ok(puzzle));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Puzzle {
+    grid: Sudoku,
+    givens: [bool; N_CELLS],
+}
+
+impl Puzzle {
+    /// Starts a new game with the filled cells of `givens` as the givens.
+    pub fn new(givens: Sudoku) -> Self {
+        Puzzle {
+            grid: givens,
+            givens: givens.clues_mask(),
+        }
+    }
+
+    /// Continues a game from its givens and the digits filled in so far.
+    ///
+    /// Returns an error for the first cell that is filled in both sudokus.
+    pub fn from_parts(givens: Sudoku, fills: Sudoku) -> Result<Self, EditError> {
+        let mut puzzle = Puzzle::new(givens);
+        for (cell, &digit) in (0..).zip(fills.0.iter()) {
+            if digit != 0 {
+                puzzle.set(cell, digit)?;
+            }
+        }
+        Ok(puzzle)
+    }
+
+    /// Reads a puzzle in the format written by its `Display` implementation, i.e. the givens and the fills
+    /// in the line format separated by a space, tab, comma or semicolon.
+    ///
+    /// If only the givens are present, the puzzle is read as a new game.
+    pub fn from_str_line(s: &str) -> Result<Self, PuzzleParseError> {
+        let givens = Sudoku::from_str_line(s).map_err(PuzzleParseError::Givens)?;
+        // a valid line sudoku consists of 81 ascii characters after the leading whitespace
+        // and may be followed by a delimiter
+        let rest = s.trim_start().get(N_CELLS + 1..).unwrap_or("");
+        if rest.trim().is_empty() {
+            return Ok(Puzzle::new(givens));
+        }
+        let fills = Sudoku::from_str_line(rest).map_err(PuzzleParseError::Fills)?;
+        Puzzle::from_parts(givens, fills).map_err(|err| match err {
+            EditError::Given { cell, .. } => PuzzleParseError::FillOnGiven(cell),
+            EditError::InvalidDigit(_) => unreachable!("sudokus contain only digits"),
+        })
+    }
+
+    /// Enters `digit` into `cell`, replacing any digit filled in before.
+    ///
+    /// Returns an error, if `cell` contains a given or `digit` is not in `1..=9`.
+    /// Conflicts with other entries are not checked.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn set(&mut self, cell: u8, digit: u8) -> Result<(), EditError> {
+        self.check_not_given(cell)?;
+        if !(1..=9).contains(&digit) {
+            return Err(EditError::InvalidDigit(digit));
+        }
+        self.grid.0[cell as usize] = digit;
+        Ok(())
+    }
+
+    /// Removes the digit filled into `cell`, if any.
+    ///
+    /// Returns an error, if `cell` contains a given.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn clear(&mut self, cell: u8) -> Result<(), EditError> {
+        self.check_not_given(cell)?;
+        self.grid.0[cell as usize] = 0;
+        Ok(())
+    }
+
+    fn check_not_given(&self, cell: u8) -> Result<(), EditError> {
+        assert!((cell as usize) < N_CELLS, "cell index out of range: {}", cell);
+        match self.givens[cell as usize] {
+            true => Err(EditError::Given {
+                cell,
+                digit: self.grid.0[cell as usize],
+            }),
+            false => Ok(()),
+        }
+    }
+
+    /// Check whether `cell` contains a given.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn is_given(&self, cell: u8) -> bool {
+        self.givens[cell as usize]
+    }
+
+    /// Returns which cells contain givens, going from left to right, top to bottom.
+    pub fn givens_mask(&self) -> [bool; N_CELLS] {
+        self.givens
+    }
+
+    /// Returns the sudoku of the givens alone.
+    pub fn givens(&self) -> Sudoku {
+        self.masked(true)
+    }
+
+    /// Returns the sudoku of the filled in digits alone.
+    pub fn fills(&self) -> Sudoku {
+        self.masked(false)
+    }
+
+    fn masked(&self, givens: bool) -> Sudoku {
+        let mut grid = self.grid.0;
+        for (num, &is_given) in grid.iter_mut().zip(self.givens.iter()) {
+            if is_given != givens {
+                *num = 0;
+            }
+        }
+        Sudoku(grid)
+    }
+
+    /// Returns the current grid with both the givens and the filled in digits.
+    pub fn grid(&self) -> Sudoku {
+        self.grid
+    }
+
+    /// Check whether the current grid is solved. See [`Sudoku::is_solved`].
+    pub fn is_solved(&self) -> bool {
+        self.grid.is_solved()
+    }
+
+    /// Returns the cells of the current grid that conflict with another entry. See [`Sudoku::conflicts`].
+    pub fn conflicts(&self) -> Vec<u8> {
+        self.grid.conflicts()
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.givens().to_str_line(),
+            self.fills().to_str_line()
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Puzzle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (self.givens(), self.fills()).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Puzzle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (givens, fills) = <(Sudoku, Sudoku)>::deserialize(deserializer)?;
+        Puzzle::from_parts(givens, fills).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,24 @@
+//! Reading sudokus from an `io::Read` without consuming more input than the sudoku itself.
+use std::io::{self, Read};
+
+// Reads bytes up to and including the next '\n' and appends them to `buf`.
+// Reads a single byte at a time, so that nothing after the line is consumed.
+// Returns `false`, if the input ended before any byte could be read.
+pub(crate) fn read_line<R: Read + ?Sized>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<bool> {
+    let mut byte = [0];
+    let mut read_any = false;
+    loop {
+        match reader.read(&mut byte) {
+            Ok(0) => return Ok(read_any),
+            Ok(_) => {
+                read_any = true;
+                buf.push(byte[0]);
+                if byte[0] == b'\n' {
+                    return Ok(true);
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+}
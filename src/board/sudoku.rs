@@ -1,20 +1,28 @@
 use rand::seq::SliceRandom;
+use rand::Rng;
 
 use crate::consts::*;
-use crate::errors::{BlockParseError, InvalidEntry, LineParseError, NotEnoughRows};
+use crate::errors::{
+    BlockParseError, Contradiction, CsvParseError, InvalidEntry, JsonParseError, LineParseError,
+    NotEnoughRows, OverlayError, PlacementError, ReadError, SolveError, Timeout,
+};
 use crate::generator::SudokuGenerator;
-use crate::solver::SudokuSolver;
+use crate::solver::{GuessStrategy, Passes, SolveSession, SudokuSolver, TreeStats, UniquenessProof};
+use crate::strategy::{Difficulty, RatingReport, SolveStats};
 
 #[cfg(feature = "serde")]
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     convert::{From, TryFrom},
-    fmt, iter, ops, slice, str,
+    fmt, io, iter, ops, slice, str,
 };
 
 /// The 9x9 sudoku board represented as an array of length 81
 type SudokuArray = [u8; N_CELLS];
 
+// version byte of `Sudoku::to_binary`
+const BINARY_FORMAT_VERSION: u8 = 1;
+
 /// The main structure exposing all the functionality of the library
 ///
 /// `Sudoku`s can generated, constructed from arrays or parsed from `&str`s
@@ -165,12 +173,63 @@ impl Symmetry {
     }
 }
 
+/// Outcome of checking a submitted answer against a puzzle with [`Sudoku::check_answer`]
+///
+/// All cell lists are in ascending order.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum AnswerResult {
+    /// The answer is a solution of the puzzle
+    Correct,
+    /// The answer contains these cells whose entries conflict with an entry in the same row, column or block
+    Invalid(Vec<u8>),
+    /// The answer is free of conflicts, but these cells are still empty
+    Incomplete(Vec<u8>),
+    /// The answer changed or removed the clues of the puzzle in these cells
+    ClueChanged(Vec<u8>),
+}
+
 impl Sudoku {
     /// Generate a random, solved sudoku
     pub fn generate_solved() -> Self {
         SudokuGenerator::generate_solved()
     }
 
+    /// Generate `n` random, solved sudokus, using `rng` as the source of randomness.
+    ///
+    /// The whole batch is reproducible from a seeded `rng`.
+    /// Scratch memory is shared between the generations, which makes this slightly faster than
+    /// generating the sudokus one by one.
+    ///
+    /// ```
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use sudoku::Sudoku;
+    ///
+    /// let batch = Sudoku::generate_solved_batch(&mut StdRng::seed_from_u64(0), 10);
+    ///
+    /// assert_eq!(batch.len(), 10);
+    /// assert!(batch.iter().all(Sudoku::is_solved));
+    /// assert_eq!(batch, Sudoku::generate_solved_batch(&mut StdRng::seed_from_u64(0), 10));
+    /// ```
+    pub fn generate_solved_batch<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Vec<Self> {
+        SudokuGenerator::generate_solved_batch(rng, n)
+    }
+
+    /// Generate a random sudoku with `n_clues` randomly placed clues, using `rng` as the source of randomness.
+    ///
+    /// No two clues conflict with each other, but unlike [`Sudoku::generate`] the clues are not chosen
+    /// to result in a unique solution. This is fast and useful for testing with partially filled inputs.
+    ///
+    /// # Panics
+    /// Panics, if `n_clues` is greater than 81.
+    pub fn generate_partial<R: Rng + ?Sized>(n_clues: usize, rng: &mut R) -> Self {
+        assert!(n_clues <= N_CELLS, "a sudoku can't have more than 81 clues");
+        let mut sudoku = SudokuGenerator::generate_solved_with_rng(rng);
+        for cell in rand::seq::index::sample(rng, N_CELLS, N_CELLS - n_clues).iter() {
+            sudoku.0[cell] = 0;
+        }
+        sudoku
+    }
+
     /// Generate a random, uniquely solvable sudoku with 180° rotational symmetry.
     ///
     /// The puzzles are minimal in that no cell can be removed without losing uniquess of the solution
@@ -205,7 +264,352 @@ impl Sudoku {
     /// Most puzzles generated by this from solved sudokus are easy.
     ///
     /// If the source `sudoku` is invalid or has multiple solutions, it will be returned as is.
-    pub fn generate_with_symmetry_from(mut sudoku: Sudoku, symmetry: Symmetry) -> Self {
+    pub fn generate_with_symmetry_from(sudoku: Sudoku, symmetry: Symmetry) -> Self {
+        Self::generate_with_symmetry_from_rng(sudoku, symmetry, &mut rand::thread_rng())
+    }
+
+    /// Generate a random, uniquely solvable sudoku that is at least as hard as `min_difficulty`
+    /// according to [`Difficulty::of`], using `rng` as the source of randomness.
+    ///
+    /// Puzzles are generated like with [`Sudoku::generate_from`] and graded until one is found that
+    /// is hard enough. Because grading is slow and hard puzzles are rare, particularly [`Difficulty::Expert`]
+    /// ones, at most 100 puzzles are tried. If none of them is hard enough, the hardest one is returned.
+    pub fn generate_hard<R: Rng + ?Sized>(rng: &mut R, min_difficulty: Difficulty) -> Self {
+        const MAX_ATTEMPTS: usize = 100;
+
+        let mut hardest = None;
+        for _ in 0..MAX_ATTEMPTS {
+            let solved = SudokuGenerator::generate_solved_with_rng(rng);
+            let sudoku = Sudoku::generate_with_symmetry_from_rng(solved, Symmetry::None, rng);
+            // the generated sudokus are always uniquely solvable
+            let difficulty = Difficulty::of(sudoku).unwrap();
+            if difficulty >= min_difficulty {
+                return sudoku;
+            }
+            match hardest {
+                Some((hardest_difficulty, _)) if hardest_difficulty >= difficulty => {}
+                _ => hardest = Some((difficulty, sudoku)),
+            }
+        }
+        hardest.unwrap().1
+    }
+
+    /// Generate a random, uniquely solvable sudoku in which at most `max_singles` empty cells are naked singles
+    /// at the start, using `rng` as the source of randomness.
+    ///
+    /// Naked singles are cells with only 1 candidate left after eliminating the digits of the filled cells
+    /// in the same row, column and block (see [`Sudoku::candidate_counts`]). Puzzles with many of them have
+    /// an obvious opening. Puzzles are generated like with [`Sudoku::generate_from`], most of which have
+    /// no more than 2 naked singles. At most 100 puzzles are tried. If none of them has few enough naked singles,
+    /// the one with the fewest is returned.
+    pub fn generate_with_max_naked_singles<R: Rng + ?Sized>(rng: &mut R, max_singles: usize) -> Self {
+        const MAX_ATTEMPTS: usize = 100;
+
+        let mut best = None;
+        for _ in 0..MAX_ATTEMPTS {
+            let solved = SudokuGenerator::generate_solved_with_rng(rng);
+            let sudoku = Sudoku::generate_with_symmetry_from_rng(solved, Symmetry::None, rng);
+            let n_singles = sudoku
+                .candidate_counts()
+                .iter()
+                .zip(sudoku.0.iter())
+                .filter(|&(&n_candidates, &num)| num == 0 && n_candidates == 1)
+                .count();
+            if n_singles <= max_singles {
+                return sudoku;
+            }
+            match best {
+                Some((fewest_singles, _)) if fewest_singles <= n_singles => {}
+                _ => best = Some((n_singles, sudoku)),
+            }
+        }
+        best.unwrap().1
+    }
+
+    /// Generate a random, uniquely solvable sudoku of exactly the given difficulty according to [`Difficulty::of`].
+    ///
+    /// Puzzles are generated like with [`Sudoku::generate_from`] and graded until one of the right difficulty is found.
+    /// Easier puzzles then get additional clues from the solution, so that they are more approachable,
+    /// as long as that doesn't change their difficulty:
+    ///
+    /// | Difficulty | Clues       |
+    /// |------------|-------------|
+    /// | Easy       | at least 30 |
+    /// | Medium     | at least 26 |
+    /// | Hard       | minimal     |
+    /// | Expert     | minimal     |
+    ///
+    /// Each attempt takes a fraction of a millisecond in release builds and only a few attempts
+    /// are necessary on average for every level. Generating a puzzle takes about 1 ms for Easy and Expert
+    /// and about 2 ms for Medium and Hard puzzles. Debug builds are many times slower.
+    pub fn generate_with_difficulty(difficulty: Difficulty) -> Self {
+        let min_clues: usize = match difficulty {
+            Difficulty::Easy => 30,
+            Difficulty::Medium => 26,
+            Difficulty::Hard | Difficulty::Expert => 0,
+        };
+        let rng = &mut rand::thread_rng();
+        loop {
+            let solved = SudokuGenerator::generate_solved_with_rng(rng);
+            let mut sudoku = Sudoku::generate_with_symmetry_from_rng(solved, Symmetry::None, rng);
+            if Difficulty::of(sudoku) != Some(difficulty) {
+                continue;
+            }
+
+            let mut empty_cells: Vec<_> = (0..N_CELLS).filter(|&cell| sudoku.0[cell] == 0).collect();
+            empty_cells.shuffle(rng);
+            let n_missing = min_clues.saturating_sub(sudoku.n_clues() as usize);
+            for &cell in &empty_cells[..n_missing] {
+                sudoku.0[cell] = solved.0[cell];
+            }
+            // additional clues can make a puzzle easier
+            if n_missing == 0 || Difficulty::of(sudoku) == Some(difficulty) {
+                return sudoku;
+            }
+        }
+    }
+
+    /// Generate a random, uniquely solvable sudoku that can be solved with `strategies` alone
+    /// and that requires at least one of them apart from naked and hidden singles.
+    ///
+    /// Puzzles are generated like with [`Sudoku::generate_from`] and solved by the [`StrategySolver`](crate::strategy::StrategySolver)
+    /// with `strategies`. A solvable puzzle is accepted, if the singles among `strategies` don't suffice to solve it.
+    /// If `strategies` contains only singles, every puzzle solvable with them is accepted.
+    ///
+    /// Puzzles that require rare strategies like [`Strategy::Jellyfish`](crate::strategy::Strategy::Jellyfish)
+    /// may not be found in a reasonable time, so at most 1000 puzzles are tried.
+    /// Returns `None`, if none of them is suitable.
+    ///
+    /// ```
+    /// use sudoku::strategy::{Difficulty, Strategy};
+    /// use sudoku::Sudoku;
+    ///
+    /// let strategies = [Strategy::NakedSingles, Strategy::HiddenSingles, Strategy::LockedCandidates];
+    /// let sudoku = Sudoku::generate_requiring(&strategies).unwrap();
+    /// assert_eq!(Difficulty::of(sudoku), Some(Difficulty::Medium));
+    /// ```
+    pub fn generate_requiring(strategies: &[crate::strategy::Strategy]) -> Option<Self> {
+        use crate::strategy::{Strategy, StrategySolver};
+        const MAX_ATTEMPTS: usize = 1000;
+
+        let singles: Vec<_> = strategies
+            .iter()
+            .filter(|strategy| matches!(strategy, Strategy::NakedSingles | Strategy::HiddenSingles))
+            .cloned()
+            .collect();
+        let has_non_singles = singles.len() != strategies.len();
+        let rng = &mut rand::thread_rng();
+        for _ in 0..MAX_ATTEMPTS {
+            let solved = SudokuGenerator::generate_solved_with_rng(rng);
+            let sudoku = Sudoku::generate_with_symmetry_from_rng(solved, Symmetry::None, rng);
+            if StrategySolver::from_sudoku(sudoku).solve(strategies).is_err() {
+                continue;
+            }
+            if has_non_singles && StrategySolver::from_sudoku(sudoku).solve(&singles).is_ok() {
+                continue;
+            }
+            return Some(sudoku);
+        }
+        None
+    }
+
+    /// Generate a random, uniquely solvable sudoku with exactly `n` clues, trying up to `attempts` times.
+    ///
+    /// Each attempt removes clues from a random solved grid in random order as long as the solution stays
+    /// unique, until only `n` clues are left. An attempt fails, if it gets stuck in a minimal puzzle with
+    /// more than `n` clues. In that case, the puzzle with the fewest clues of all attempts is returned.
+    ///
+    /// The lower `n`, the less likely an attempt is to succeed. Minimal puzzles generated this way
+    /// usually have 22 to 26 clues, so for `n` above that range the first attempt practically always succeeds,
+    /// but below about 21 clues successes become very rare and close to 17, the lowest possible count,
+    /// even thousands of attempts will usually only produce the fallback. Every attempt solves
+    /// the grid up to 81 times, so large numbers of attempts are slow.
+    ///
+    /// Returns `None`, if `attempts` is 0.
+    pub fn generate_with_clues_retry(n: usize, attempts: usize) -> Option<Self> {
+        let rng = &mut rand::thread_rng();
+        let mut closest: Option<Sudoku> = None;
+        for _ in 0..attempts {
+            let mut sudoku = SudokuGenerator::generate_solved_with_rng(rng);
+            let mut n_clues = N_CELLS;
+            for cell in rand::seq::index::sample(rng, N_CELLS, N_CELLS).iter() {
+                if n_clues <= n {
+                    break;
+                }
+                let mut sudoku_tmp = sudoku;
+                sudoku_tmp.0[cell] = 0;
+                if sudoku_tmp.is_uniquely_solvable() {
+                    sudoku = sudoku_tmp;
+                    n_clues -= 1;
+                }
+            }
+            if n_clues <= n {
+                return Some(sudoku);
+            }
+            match closest {
+                Some(closest) if closest.n_clues() as usize <= n_clues => {}
+                _ => closest = Some(sudoku),
+            }
+        }
+        closest
+    }
+
+    /// Generate a random, uniquely solvable sudoku in which all digits appear about equally often as clues,
+    /// using `rng` as the source of randomness.
+    ///
+    /// Clues are removed in random order as with [`Sudoku::generate_from`], except that a clue is kept
+    /// if removing it would make its digit appear more than 2 times less often than the most common digit.
+    /// Removal is repeated until no more clues can be removed, so the puzzle is minimal
+    /// under that constraint, though it usually has a few more clues than an unbalanced puzzle.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::generate_balanced(&mut rand::thread_rng());
+    /// let mut counts = [0; 9];
+    /// for digit in sudoku.iter().flatten() {
+    ///     counts[digit as usize - 1] += 1;
+    /// }
+    /// assert!(counts.iter().max().unwrap() - counts.iter().min().unwrap() <= 2);
+    /// assert!(sudoku.is_uniquely_solvable());
+    /// ```
+    pub fn generate_balanced<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        const MAX_SPREAD: u8 = 2;
+
+        let mut sudoku = SudokuGenerator::generate_solved_with_rng(rng);
+        let mut digit_counts = [9u8; 9];
+        let mut cell_order = [0; N_CELLS];
+        cell_order
+            .iter_mut()
+            .enumerate()
+            .for_each(|(cell, place)| *place = cell);
+        cell_order.shuffle(rng);
+
+        // clues whose removal would lose uniqueness stay necessary when more clues are removed
+        let mut is_necessary = [false; N_CELLS];
+        // clues that were kept for balance may become removable after other digits were removed
+        let mut removed_any = true;
+        while removed_any {
+            removed_any = false;
+            for &cell in &cell_order[..] {
+                let digit = sudoku.0[cell];
+                if digit == 0 || is_necessary[cell] {
+                    continue;
+                }
+                let max_count = *digit_counts.iter().max().unwrap();
+                if max_count - (digit_counts[digit as usize - 1] - 1) > MAX_SPREAD {
+                    continue;
+                }
+                let mut sudoku_tmp = sudoku;
+                sudoku_tmp.0[cell] = 0;
+                if sudoku_tmp.is_uniquely_solvable() {
+                    sudoku = sudoku_tmp;
+                    digit_counts[digit as usize - 1] -= 1;
+                    removed_any = true;
+                } else {
+                    is_necessary[cell] = true;
+                }
+            }
+        }
+        sudoku
+    }
+
+    /// Generate up to `max` different minimal puzzles by removing clues from `filled` in random orders,
+    /// using `rng` as the source of randomness. All puzzles have the same solution as `filled`.
+    ///
+    /// Puzzles are minimal in that no clue can be removed without losing uniqueness of the solution.
+    /// Only one puzzle of each equivalence class is returned (see [`Sudoku::canonicalized`]).
+    /// Because a solution can have fewer than `max` different minimal puzzles and duplicates are
+    /// likely for some grids, the search is stopped after `10 * max` attempts, so fewer puzzles may be returned.
+    ///
+    /// If `filled` doesn't have a unique solution, no puzzles are returned. It need not be solved.
+    pub fn minimal_puzzles<R: Rng + ?Sized>(filled: &Sudoku, rng: &mut R, max: usize) -> Vec<Sudoku> {
+        let mut puzzles = vec![];
+        if !filled.is_uniquely_solvable() {
+            return puzzles;
+        }
+        let mut seen = std::collections::HashSet::new();
+        for _ in 0..max.saturating_mul(10) {
+            if puzzles.len() == max {
+                break;
+            }
+            let puzzle = Sudoku::generate_with_symmetry_from_rng(*filled, Symmetry::None, rng);
+            // the puzzles are proper
+            if seen.insert(puzzle.canonical_line().unwrap()) {
+                puzzles.push(puzzle);
+            }
+        }
+        puzzles
+    }
+
+    /// Randomly changes a proper puzzle into a different proper puzzle with the same solution,
+    /// using `rng` as the source of randomness.
+    ///
+    /// A single random change is made, which is one of
+    /// - removing a clue
+    /// - adding a clue from the solution
+    /// - moving a clue, i.e. removing one and adding a different one from the solution
+    ///
+    /// Changes that lose the uniqueness of the solution are not made.
+    /// Returns `None`, if the sudoku doesn't have a unique solution or no change can keep it unique.
+    pub fn perturb<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<Sudoku> {
+        enum Perturbation {
+            Remove,
+            Add,
+            Move,
+        }
+
+        let solution = self.solution()?;
+        let (mut clues, mut empty_cells): (Vec<usize>, Vec<usize>) =
+            (0..N_CELLS).partition(|&cell| self.0[cell] != 0);
+        clues.shuffle(rng);
+        empty_cells.shuffle(rng);
+        let mut perturbations = [Perturbation::Remove, Perturbation::Add, Perturbation::Move];
+        perturbations.shuffle(rng);
+
+        let without_clue = |cell: usize| {
+            let mut sudoku = *self;
+            sudoku.0[cell] = 0;
+            Some(sudoku).filter(|sudoku| sudoku.is_uniquely_solvable())
+        };
+
+        for perturbation in perturbations.iter() {
+            match perturbation {
+                Perturbation::Remove => {
+                    if let Some(sudoku) = clues.iter().find_map(|&cell| without_clue(cell)) {
+                        return Some(sudoku);
+                    }
+                }
+                // adding a clue of the solution can't make it ambiguous
+                Perturbation::Add => {
+                    if let Some(&cell) = empty_cells.first() {
+                        let mut sudoku = *self;
+                        sudoku.0[cell] = solution.0[cell];
+                        return Some(sudoku);
+                    }
+                }
+                Perturbation::Move => {
+                    for &from in &clues {
+                        for &to in &empty_cells {
+                            let mut sudoku = *self;
+                            sudoku.0[from] = 0;
+                            sudoku.0[to] = solution.0[to];
+                            if sudoku.is_uniquely_solvable() {
+                                return Some(sudoku);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn generate_with_symmetry_from_rng<R: Rng + ?Sized>(
+        mut sudoku: Sudoku,
+        symmetry: Symmetry,
+        rng: &mut R,
+    ) -> Self {
         // this function is following
         // the approach outlined here: https://stackoverflow.com/a/7280517
         //
@@ -219,7 +623,7 @@ impl Sudoku {
             .iter_mut()
             .enumerate()
             .for_each(|(cell, place)| *place = cell);
-        cell_order.shuffle(&mut rand::thread_rng());
+        cell_order.shuffle(rng);
 
         // With symmetries, many cells are equivalent.
         // If we've already visited one cell in a symmetry class, we can skip ahead
@@ -264,6 +668,42 @@ impl Sudoku {
         Self::_from_bytes(&bytes)
     }
 
+    /// Creates a sudoku from a byte array, in which the digits are numbered from 0-8 instead of 1-9
+    /// and empty cells are denoted by `empty_marker`. This is the inverse of [`Sudoku::to_bytes_zero_based`].
+    ///
+    /// Returns an error, if `empty_marker` is a digit from 0-8 or a byte is neither a digit nor the marker.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut bytes = [255; 81];
+    /// bytes[0] = 0;
+    /// bytes[1] = 8;
+    /// let sudoku = Sudoku::from_bytes_zero_based(bytes, 255).unwrap();
+    ///
+    /// assert_eq!(sudoku.to_bytes()[..3], [1, 9, 0]);
+    /// assert!(Sudoku::from_bytes_zero_based(bytes, 0).is_err());
+    /// ```
+    pub fn from_bytes_zero_based(
+        bytes: SudokuArray,
+        empty_marker: u8,
+    ) -> Result<Sudoku, crate::errors::FromZeroBasedError> {
+        use crate::errors::FromZeroBasedError;
+
+        if empty_marker < 9 {
+            return Err(FromZeroBasedError::InvalidEmptyMarker(empty_marker));
+        }
+        let mut sudoku = Sudoku([0; N_CELLS]);
+        for ((cell, num), &byte) in (0..).zip(sudoku.0.iter_mut()).zip(bytes.iter()) {
+            *num = match byte {
+                0..=8 => byte + 1,
+                _ if byte == empty_marker => 0,
+                _ => return Err(FromZeroBasedError::InvalidEntry { cell, byte }),
+            };
+        }
+        Ok(sudoku)
+    }
+
     fn _from_bytes(bytes: &SudokuArray) -> Result<Sudoku, crate::errors::FromBytesError> {
         match bytes.iter().fold(true, |valid, &byte| valid & (byte <= 9)) {
             true => Ok(Sudoku(*bytes)),
@@ -271,6 +711,127 @@ impl Sudoku {
         }
     }
 
+    /// Creates a sudoku from `(cell, digit)` entries. All other cells are empty.
+    ///
+    /// Cells must be in `0..81` and digits in `1..=9`. Entering the same digit into a cell multiple times
+    /// is allowed, entering different ones is an error. Conflicts between different cells are not checked.
+    ///
+    /// ```
+    /// use sudoku::errors::FromEntriesError;
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_entries(vec![(0, 5), (80, 9), (0, 5)]).unwrap();
+    /// assert_eq!(sudoku.to_bytes()[0], 5);
+    /// assert_eq!(sudoku.n_clues(), 2);
+    ///
+    /// assert_eq!(
+    ///     Sudoku::from_entries(vec![(0, 5), (0, 6)]),
+    ///     Err(FromEntriesError::ConflictingEntries { cell: 0, digit: 5, other_digit: 6 })
+    /// );
+    /// ```
+    pub fn from_entries<I: IntoIterator<Item = (u8, u8)>>(
+        entries: I,
+    ) -> Result<Sudoku, crate::errors::FromEntriesError> {
+        use crate::errors::FromEntriesError;
+
+        let mut sudoku = Sudoku([0; N_CELLS]);
+        for (cell, digit) in entries {
+            let entry = sudoku
+                .0
+                .get_mut(cell as usize)
+                .ok_or(FromEntriesError::InvalidCell(cell))?;
+            if !(1..=9).contains(&digit) {
+                return Err(FromEntriesError::InvalidDigit { cell, digit });
+            }
+            match *entry {
+                0 => *entry = digit,
+                num if num == digit => {}
+                num => {
+                    return Err(FromEntriesError::ConflictingEntries {
+                        cell,
+                        digit: num,
+                        other_digit: digit,
+                    })
+                }
+            }
+        }
+        Ok(sudoku)
+    }
+
+    /// Creates a sudoku from the packed representation returned by [`Sudoku::to_packed`].
+    /// All cells must be below 10 and the padding must be 0.
+    pub fn from_packed(packed: &[u8; 41]) -> Result<Sudoku, crate::errors::FromPackedError> {
+        let mut bytes = [0; N_CELLS];
+        for (pair, &byte) in bytes.chunks_mut(2).zip(packed.iter()) {
+            pair[0] = byte >> 4;
+            if let Some(second) = pair.get_mut(1) {
+                *second = byte & 0xF;
+            }
+        }
+        match packed[40] & 0xF == 0 && bytes.iter().all(|&byte| byte <= 9) {
+            true => Ok(Sudoku(bytes)),
+            false => Err(crate::errors::FromPackedError(())),
+        }
+    }
+
+    /// Reads a sudoku from the short code returned by [`Sudoku::to_code`].
+    pub fn from_code(code: &str) -> Result<Sudoku, crate::errors::FromCodeError> {
+        let packed = super::code::decode(code)?;
+        Sudoku::from_packed(&packed).map_err(crate::errors::FromCodeError::InvalidEntries)
+    }
+
+    /// Reads a sudoku from the binary encoding returned by [`Sudoku::to_binary`].
+    pub fn from_binary(binary: &[u8]) -> Result<Sudoku, crate::errors::FromBinaryError> {
+        use crate::errors::FromBinaryError;
+        use std::convert::TryInto;
+
+        let (&version, packed) = binary.split_first().ok_or(FromBinaryError::WrongLength(0))?;
+        if version != BINARY_FORMAT_VERSION {
+            return Err(FromBinaryError::UnsupportedVersion(version));
+        }
+        let packed: &[u8; 41] = packed
+            .try_into()
+            .map_err(|_| FromBinaryError::WrongLength(binary.len()))?;
+        Sudoku::from_packed(packed).map_err(FromBinaryError::InvalidEntries)
+    }
+
+    /// Reads a sudoku from nested JSON arrays, one array of 9 numbers per row with 0 for empty cells.
+    /// This is the format produced by [`Sudoku::to_json_array`]. Whitespace between the elements is allowed.
+    ///
+    /// Only this array structure is parsed, so the serde dependency is not needed.
+    pub fn from_json_array(s: &str) -> Result<Sudoku, JsonParseError> {
+        super::json::from_json_array(s).map(Sudoku)
+    }
+
+    /// Reads a sudoku from comma-separated values, one line of 9 fields per row.
+    /// This is the format produced by [`Sudoku::to_csv`] and by spreadsheet applications.
+    ///
+    /// Empty fields and `0` are empty cells, `1` to `9` are clues. Whitespace around the fields
+    /// is ignored, as are lines that contain only whitespace.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_csv(
+    ///     "
+    ///     ,,3,,2,,6,,
+    ///     9,,,3,,5,,,1
+    ///     ,,1,8,,6,4,,
+    ///     ,,8,1,,2,9,,
+    ///     7,,,,,,,,8
+    ///     ,,6,7,,8,2,,
+    ///     ,,2,6,,9,5,,
+    ///     8,,,2,,3,,,9
+    ///     ,,5, 0 ,1, 0 ,3,,
+    ///     ",
+    /// );
+    /// let line = "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..";
+    /// assert_eq!(sudoku.unwrap(), Sudoku::from_str_line(line).unwrap());
+    /// ```
+    pub fn from_csv(s: &str) -> Result<Sudoku, CsvParseError> {
+        super::csv::from_csv(s).map(Sudoku)
+    }
+
     /// Reads a sudoku in the line format.
     ///
     /// This is a concatenation of the digits in each cell, line by line from top to bottom.
@@ -281,6 +842,8 @@ impl Sudoku {
     /// separated by ASCII whitespace, commas or semicolons,
     /// that is, any of ' ', '\t', '\n', '\r', ',', ';'
     ///
+    /// Leading whitespace before the sudoku is ignored.
+    ///
     /// Example:
     ///
     /// ```text
@@ -289,6 +852,7 @@ impl Sudoku {
     ///
     /// Stops parsing after the first sudoku
     pub fn from_str_line(s: &str) -> Result<Sudoku, LineParseError> {
+        let s = s.trim_start();
         let chars = s.as_bytes();
         if let Ok(sudoku) = Sudoku::_from_str_line_fast_path(chars) {
             return Ok(sudoku);
@@ -333,6 +897,76 @@ impl Sudoku {
         Ok(Sudoku(grid))
     }
 
+    /// Reads a sudoku in the line format like [`Sudoku::from_str_line`] and returns the comment after it.
+    ///
+    /// The comment is everything after the delimiter following the 81 cells with surrounding whitespace removed.
+    /// If there is no comment or it is empty, `None` is returned for it.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let line = "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1... easy; from a book";
+    /// let (sudoku, comment) = Sudoku::from_str_line_with_comment(line).unwrap();
+    ///
+    /// assert_eq!(Ok(sudoku), Sudoku::from_str_line(line));
+    /// assert_eq!(comment, Some("easy; from a book"));
+    /// ```
+    pub fn from_str_line_with_comment(s: &str) -> Result<(Sudoku, Option<&str>), LineParseError> {
+        let sudoku = Sudoku::from_str_line(s)?;
+        // a valid line sudoku consists of 81 ascii characters after the leading whitespace
+        // and, if anything follows, a single ascii delimiter
+        let comment = s
+            .trim_start()
+            .get(N_CELLS + 1..)
+            .map(str::trim)
+            .filter(|comment| !comment.is_empty());
+        Ok((sudoku, comment))
+    }
+
+    /// Reads a sudoku in the line format with the letters `A` to `I` as clues.
+    ///
+    /// Works like [`Sudoku::from_str_line`], but the letters `'A'..='I'` stand for the digits 1-9.
+    /// Lower case letters are accepted as well.
+    /// `'_'`, `'.'` and `'0'` are accepted interchangeably as empty cells.
+    /// The digits 1-9 are rejected.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let letters = "..C.B.F..I..C.E..A..AH.FD....HA.BI..G.......H..FG.HB....BF.IE..H..B.C..I..E.A.C..";
+    /// let digits = "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..";
+    /// assert_eq!(Sudoku::from_str_line_letters(letters), Sudoku::from_str_line(digits));
+    /// ```
+    pub fn from_str_line_letters(s: &str) -> Result<Sudoku, LineParseError> {
+        let s = s.trim_start();
+        let mut chars = s.chars();
+
+        let mut grid = [0; N_CELLS];
+        for (i, cell) in (0..).zip(grid.iter_mut()) {
+            *cell = match chars.next() {
+                Some('_') | Some('.') | Some('0') => 0,
+                Some(ch @ 'A'..='I') => ch as u8 - b'A' + 1,
+                Some(ch @ 'a'..='i') => ch as u8 - b'a' + 1,
+                // space ends sudoku before grid is filled
+                None | Some(' ') | Some('\t') => return Err(LineParseError::NotEnoughCells(i)),
+                Some(ch) => return Err(LineParseError::InvalidEntry(InvalidEntry { cell: i, ch })),
+            };
+        }
+
+        match chars.next() {
+            // end of string or delimiters, end of sudoku
+            None | Some('\t') | Some(' ') | Some('\r') | Some('\n') | Some(';') | Some(',') => {
+                Ok(Sudoku(grid))
+            }
+            // valid cell entry => too long
+            Some('_') | Some('.') | Some('0') | Some('A'..='I') | Some('a'..='i') => {
+                Err(LineParseError::TooManyCells)
+            }
+            // treat like comment, but with missing delimiter
+            Some(_) => Err(LineParseError::MissingCommentDelimiter),
+        }
+    }
+
     /// Parses sudokus under the assumption that everything is valid.
     /// Checks only once the end if the assumption was valid.
     //
@@ -402,6 +1036,9 @@ impl Sudoku {
     /// __5|_1_|3__
     /// ```
     ///
+    /// Empty lines (whitespace ignored) between the rows of the sudoku are skipped, e.g. to separate bands.
+    /// They don't count as rows.
+    ///
     /// Stops parsing after the first sudoku
     pub fn from_str_block(s: &str) -> Result<Sudoku, BlockParseError> {
         let mut grid = [0; N_CELLS];
@@ -416,7 +1053,9 @@ impl Sudoku {
 
         // Read a row per line
         let mut n_line_sud = 0;
-        for (n_line_str, line) in s.lines().enumerate() {
+        // number of lines read so far, excluding skipped empty lines
+        let mut n_lines_read = 0;
+        for line in s.lines() {
             // if sudoku complete
             // enforce empty line (whitespace ignored)
             // Maybe allow comment lines in the future
@@ -426,6 +1065,12 @@ impl Sudoku {
                     false => return Err(BlockParseError::TooManyRows),
                 }
             }
+            // skip empty lines between rows
+            if n_line_sud != 0 && line.trim().is_empty() {
+                continue;
+            }
+            let n_line_str = n_lines_read;
+            n_lines_read += 1;
 
             // if delimited, check horizontal field delimiters and skip over line
             if (format == Format::Delimited || format == Format::DelimitedPlus)
@@ -508,6 +1153,117 @@ impl Sudoku {
         Ok(Sudoku(grid))
     }
 
+    /// Reads a sudoku in the block format drawn with Unicode box-drawing characters.
+    ///
+    /// Box-drawing characters (`U+2500` to `U+257F`, e.g. `┌─┬┐│┼`) and whitespace are ignored.
+    /// Lines that contain nothing else are skipped, all other lines must contain exactly 9 cells.
+    /// Digits must be in range of 1-9.
+    /// `'_'`, `'.'` and `'0'` are accepted interchangeably as empty cells.
+    /// Any other character is rejected.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_box_drawing("
+    /// ┌───────┬───────┬───────┐
+    /// │ . . 3 │ . 2 . │ 6 . . │
+    /// │ 9 . . │ 3 . 5 │ . . 1 │
+    /// │ . . 1 │ 8 . 6 │ 4 . . │
+    /// ├───────┼───────┼───────┤
+    /// │ . . 8 │ 1 . 2 │ 9 . . │
+    /// │ 7 . . │ . . . │ . . 8 │
+    /// │ . . 6 │ 7 . 8 │ 2 . . │
+    /// ├───────┼───────┼───────┤
+    /// │ . . 2 │ 6 . 9 │ 5 . . │
+    /// │ 8 . . │ 2 . 3 │ . . 9 │
+    /// │ . . 5 │ . 1 . │ 3 . . │
+    /// └───────┴───────┴───────┘
+    /// ");
+    /// assert!(sudoku.is_ok());
+    /// ```
+    pub fn from_str_box_drawing(s: &str) -> Result<Sudoku, BlockParseError> {
+        let is_ignored = |ch: char| ch.is_whitespace() || ('\u{2500}'..='\u{257F}').contains(&ch);
+
+        let mut grid = [0; N_CELLS];
+        let mut n_rows = 0;
+        for line in s.lines() {
+            if line.chars().all(is_ignored) {
+                continue;
+            }
+            if n_rows == 9 {
+                return Err(BlockParseError::TooManyRows);
+            }
+
+            let mut n_cols = 0;
+            for ch in line.chars().filter(|&ch| !is_ignored(ch)) {
+                if n_cols == 9 {
+                    return Err(BlockParseError::InvalidLineLength(n_rows));
+                }
+                let cell = n_rows * 9 + n_cols;
+                grid[cell as usize] = match ch {
+                    '_' | '.' => 0,
+                    '0'..='9' => ch as u8 - b'0',
+                    _ => return Err(BlockParseError::InvalidEntry(InvalidEntry { cell, ch })),
+                };
+                n_cols += 1;
+            }
+            if n_cols != 9 {
+                return Err(BlockParseError::InvalidLineLength(n_rows));
+            }
+            n_rows += 1;
+        }
+        if n_rows != 9 {
+            return Err(BlockParseError::NotEnoughRows(n_rows));
+        }
+        Ok(Sudoku(grid))
+    }
+
+    /// Reads a sudoku in the line format from `reader`. See [`Sudoku::from_str_line`] for the format.
+    ///
+    /// Reading stops at the end of the line containing the sudoku, so the rest of the input can be read afterwards
+    /// by passing a `&mut` reference. Leading empty lines are skipped. The input is read one byte at a time,
+    /// so unbuffered readers like [`File`](std::fs::File) should be wrapped in a [`BufReader`](std::io::BufReader).
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut input = "\
+    /// ...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...
+    /// ..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..
+    /// "
+    /// .as_bytes();
+    /// let first = Sudoku::from_reader_line(&mut input).unwrap();
+    /// let second = Sudoku::from_reader_line(&mut input).unwrap();
+    /// assert_ne!(first, second);
+    /// assert!(input.is_empty());
+    /// ```
+    pub fn from_reader_line<R: io::Read>(mut reader: R) -> Result<Sudoku, ReadError<LineParseError>> {
+        let mut buf = vec![];
+        while buf.iter().all(u8::is_ascii_whitespace) && super::reader::read_line(&mut reader, &mut buf)? {}
+        Sudoku::from_str_line(&String::from_utf8_lossy(&buf)).map_err(ReadError::Parse)
+    }
+
+    /// Reads a sudoku in the block format from `reader`. See [`Sudoku::from_str_block`] for the format.
+    ///
+    /// Reading stops at the end of the 9th row, so the rest of the input can be read afterwards
+    /// by passing a `&mut` reference. Leading empty lines are skipped. The input is read one byte at a time,
+    /// so unbuffered readers like [`File`](std::fs::File) should be wrapped in a [`BufReader`](std::io::BufReader).
+    pub fn from_reader_block<R: io::Read>(mut reader: R) -> Result<Sudoku, ReadError<BlockParseError>> {
+        let mut buf = vec![];
+        loop {
+            let is_eof = !super::reader::read_line(&mut reader, &mut buf)?;
+            if !is_eof && buf.iter().all(u8::is_ascii_whitespace) {
+                buf.clear();
+                continue;
+            }
+            match Sudoku::from_str_block(&String::from_utf8_lossy(&buf)) {
+                // more rows may follow
+                Err(BlockParseError::NotEnoughRows(_)) if !is_eof => continue,
+                result => return result.map_err(ReadError::Parse),
+            }
+        }
+    }
+
     /// Reads a sudoku in a variety of block formats with very few constraints.
     ///
     /// '_', '.' and '0' are treated as empty cells. '1' to '9' as clues.
@@ -519,14 +1275,60 @@ impl Sudoku {
     /// Due to the lax format rules, the only failure that can occur
     /// is that there are not enough rows.
     pub fn from_str_block_permissive(s: &str) -> Result<Sudoku, NotEnoughRows> {
+        Sudoku::from_str_block_permissive_with(s, |_| false)
+    }
+
+    /// Reads a sudoku in a variety of block formats with very few constraints
+    /// and additional characters for empty cells.
+    ///
+    /// Works like [`Sudoku::from_str_block_permissive`], but any character for which
+    /// `matches_empty_cell` returns `true` is also treated as an empty cell.
+    /// '_', '.' and '0' are always empty cells and '1' to '9' are always clues.
+    /// The indentation shared by all lines that aren't blank is skipped, so indented blocks can use spaces
+    /// for empty cells, too, as long as one of the rows doesn't start with an empty cell.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_block_permissive_with(
+    ///     "
+    ///     XX3X2X6XX
+    ///     9**3*5**1
+    ///     **18*64**
+    ///     **81*29**
+    ///     7*******8
+    ///     **67*82**
+    ///     **26*95**
+    ///     8**2*3**9
+    ///     **5*1*3**
+    ///     ",
+    ///     |ch| ch == '*' || ch == 'X',
+    /// );
+    /// assert!(sudoku.is_ok());
+    /// ```
+    pub fn from_str_block_permissive_with<CP>(
+        s: &str,
+        matches_empty_cell: CP,
+    ) -> Result<Sudoku, NotEnoughRows>
+    where
+        CP: Fn(char) -> bool,
+    {
         let mut grid = [0; N_CELLS];
 
         let mut valid_rows = 0;
+        // indentation shared by all lines isn't part of the rows, even if `matches_empty_cell` accepts whitespace
+        let indentation = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
         for line in s.lines() {
+            let line = line.get(indentation..).unwrap_or_else(|| line.trim_start());
             let mut row_vals = [0; 9];
             let mut nums_in_row = 0;
             for ch in line.chars() {
-                if ['.', '_'].contains(&ch) {
+                if ['.', '_'].contains(&ch) || (!ch.is_ascii_digit() && matches_empty_cell(ch)) {
                     row_vals[nums_in_row] = 0;
                     nums_in_row += 1;
                 } else if '0' <= ch && ch <= '9' {
@@ -559,12 +1361,126 @@ impl Sudoku {
         }
     }
 
-    /// Solve sudoku and return solution if solution is unique.
-    pub fn solution(self) -> Option<Sudoku> {
-        // without at least 8 digits present, sudoku has multiple solutions
-        // bitmask
-        let mut nums_contained: u16 = 0;
-        // same with less than 17 clues
+    /// Find a solution to the sudoku like [`Sudoku::some_solution`].
+    /// If no solution exists, the first contradiction found by entering the clues and deducing
+    /// naked and hidden singles is returned instead.
+    ///
+    /// ```
+    /// use sudoku::{errors::Contradiction, Sudoku};
+    ///
+    /// let mut grid = [0; 81];
+    /// grid[0] = 1;
+    /// grid[5] = 1;
+    /// let sudoku = Sudoku::from_bytes(grid).unwrap();
+    /// assert!(matches!(
+    ///     sudoku.some_solution_or_contradiction(),
+    ///     Err(Contradiction::ConflictingEntry { digit: 1, .. })
+    /// ));
+    /// ```
+    pub fn some_solution_or_contradiction(self) -> Result<Sudoku, Contradiction> {
+        match self.some_solution() {
+            Some(solution) => Ok(solution),
+            None => Err(SudokuGenerator::find_contradiction(self).unwrap_or(Contradiction::SearchExhausted)),
+        }
+    }
+
+    /// Find a solution to the sudoku like [`Sudoku::some_solution`], but deduce as much as possible
+    /// with the human [strategies](crate::strategy::Strategy) first and only guess when they get stuck.
+    ///
+    /// Strategies that assume a unique solution are not used, so the returned grid is a solution
+    /// even if the sudoku has more than one.
+    /// Return `None` if no solution exists.
+    pub fn solve_one_logical_first(&self) -> Option<Sudoku> {
+        use crate::strategy::StrategySolver;
+
+        match StrategySolver::from_sudoku(*self).solve(&strategies_without_uniqueness()) {
+            Ok((solved, _)) if solved.is_solved() => Some(solved),
+            Ok(_) => None,
+            Err((stuck, _)) => stuck.some_solution(),
+        }
+    }
+
+    /// Find a solution to the sudoku like [`Sudoku::some_solution`], in which every cell contains one of
+    /// the digits allowed for it.
+    ///
+    /// `allowed[cell]` is a bitmask of the digits permitted in `cell`, bit `n` standing for digit `n + 1`,
+    /// i.e. the same layout as [`Set::bits`](crate::bitset::Set::bits). This can express constraints
+    /// like "this cell must be even". Return `None` if no such solution exists, which includes
+    /// clues that are not allowed in their cell.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    /// let mut allowed = [0b1_1111_1111; 81];
+    /// // only odd digits on the diagonal
+    /// for cell in (0..81).step_by(10) {
+    ///     allowed[cell] = 0b1_0101_0101;
+    /// }
+    /// let solution = empty.solve_with_constraints(&allowed).unwrap();
+    ///
+    /// assert!(solution.to_bytes().iter().step_by(10).all(|digit| digit % 2 == 1));
+    /// ```
+    pub fn solve_with_constraints(&self, allowed: &[u16; N_CELLS]) -> Option<Sudoku> {
+        let mut solver = SudokuSolver::from_sudoku(*self).ok()?;
+        solver.restrict_candidates(allowed).ok()?;
+        solver.solutions_up_to(1).pop()
+    }
+
+    /// Find the lexicographically smallest solution to the sudoku, comparing solutions
+    /// by their cells from left to right, top to bottom.
+    /// Unlike [`Sudoku::some_solution`], the result is deterministic if multiple solutions exist.
+    /// Return `None` if no solution exists.
+    pub fn smallest_solution(self) -> Option<Sudoku> {
+        SudokuSolver::from_sudoku(self)
+            .ok()
+            .and_then(SudokuSolver::smallest_solution)
+    }
+
+    /// Find the `limit` lexicographically smallest solutions to the sudoku in ascending order, comparing solutions
+    /// by their cells from left to right, top to bottom. If less solutions exist, return only those.
+    ///
+    /// Unlike with [`Sudoku::solutions_up_to`], the result is deterministic and won't change across versions,
+    /// which makes it suitable for snapshot tests. The search is somewhat slower, because it can't choose
+    /// the cells to guess on freely.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    /// let solutions = empty.smallest_solutions(3);
+    ///
+    /// assert_eq!(solutions[0], empty.smallest_solution().unwrap());
+    /// assert!(solutions.windows(2).all(|pair| pair[0] < pair[1]));
+    /// ```
+    pub fn smallest_solutions(self, limit: usize) -> Vec<Sudoku> {
+        SudokuSolver::from_sudoku(self)
+            .map(|solver| solver.smallest_solutions(limit))
+            .unwrap_or_default()
+    }
+
+    /// Apply the deductions of the solver until it would have to guess and return
+    /// the resulting grid together with the candidates of each cell.
+    ///
+    /// The solver only deduces naked and hidden singles and locked candidates. If these suffice,
+    /// the solved sudoku is returned and all candidate sets are empty. Otherwise, the candidates of
+    /// the cells that are still empty are the ones left at the point of the first guess.
+    /// Filled cells have no candidates.
+    ///
+    /// Returns `None`, if a contradiction is found, which means that the sudoku has no solution.
+    /// A sudoku for which this returns `Some` may still be unsolvable.
+    pub fn solve_until_guess(self) -> Option<(Sudoku, [crate::bitset::Set<crate::board::Digit>; N_CELLS])> {
+        SudokuSolver::from_sudoku(self)
+            .ok()
+            .and_then(SudokuSolver::solve_until_guess)
+    }
+
+    /// Solve sudoku and return solution if solution is unique.
+    pub fn solution(self) -> Option<Sudoku> {
+        // without at least 8 digits present, sudoku has multiple solutions
+        // bitmask
+        let mut nums_contained: u16 = 0;
+        // same with less than 17 clues
         let mut n_clues = 0;
         self.iter().filter_map(|id| id).for_each(|num| {
             nums_contained |= 1 << num;
@@ -582,6 +1498,296 @@ impl Sudoku {
         }
     }
 
+    /// Solve the sudoku in place, if it has a unique solution.
+    /// Otherwise, the sudoku is left unchanged and the reason is returned.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    /// use sudoku::errors::SolveError;
+    ///
+    /// let mut sudoku = Sudoku::from_bytes([0; 81]).unwrap();
+    /// assert_eq!(sudoku.try_solve(), Err(SolveError::MultipleSolutions));
+    /// assert!(sudoku.is_empty());
+    /// ```
+    pub fn try_solve(&mut self) -> Result<(), SolveError> {
+        let mut solution = [[0; N_CELLS]];
+        match self.solutions_up_to_buffer(&mut solution, 2) {
+            0 => Err(SolveError::NoSolution),
+            1 => {
+                self.0 = solution[0];
+                Ok(())
+            }
+            _ => Err(SolveError::MultipleSolutions),
+        }
+    }
+
+    /// Solve sudoku and return its unique solution.
+    /// This is a convenience for tests and other contexts where only proper puzzles are expected.
+    ///
+    /// # Panics
+    /// Panics, if the sudoku has no solution or more than one. Use [`Sudoku::solution`] to handle these cases.
+    pub fn expect_solution(self) -> Sudoku {
+        let mut solution = [[0; N_CELLS]];
+        match self.solutions_up_to_buffer(&mut solution, 2) {
+            0 => panic!("sudoku has no solution: {}", self),
+            1 => Sudoku(solution[0]),
+            _ => panic!("sudoku has multiple solutions: {}", self),
+        }
+    }
+
+    /// Returns the [names](crate::strategy::Strategy::name) of the strategies used to solve the sudoku
+    /// logically, one per deduction in the order they were made.
+    ///
+    /// All strategies that are used for [grading](crate::strategy::Difficulty) are tried with the easiest first.
+    /// Returns `None`, if the sudoku doesn't have a unique solution or can't be solved without guessing.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let path = sudoku.technique_path().unwrap();
+    ///
+    /// assert!(path.iter().any(|name| name == "Locked Candidates"));
+    /// ```
+    pub fn technique_path(&self) -> Option<Vec<String>> {
+        use crate::strategy::{Strategy, StrategySolver};
+
+        if !self.is_uniquely_solvable() {
+            return None;
+        }
+        let (_, deductions) = StrategySolver::from_sudoku(*self).solve(Strategy::ALL).ok()?;
+        Some(
+            deductions
+                .iter()
+                .map(|deduction| deduction.strategy().name().to_string())
+                .collect(),
+        )
+    }
+
+    /// Returns every cell that can be filled in right now by a single naked or hidden single,
+    /// together with its digit and the strategy that forces it, ordered by cell.
+    ///
+    /// Only the candidates left by the filled cells (see [`Sudoku::candidate_counts`]) are considered.
+    /// Unlike a logical solve, the cells found are not entered, so this is the batch of all moves that
+    /// are available at once. A cell that is both a naked and a hidden single is reported as a naked single.
+    ///
+    /// ```
+    /// use sudoku::{strategy::Strategy, Sudoku};
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let solution = sudoku.solution().unwrap();
+    /// let forced = sudoku.all_forced();
+    ///
+    /// assert!(!forced.is_empty());
+    /// for (cell, digit, _) in forced {
+    ///     assert_eq!(solution.to_bytes()[cell as usize], digit);
+    /// }
+    /// ```
+    pub fn all_forced(&self) -> Vec<(u8, u8, crate::strategy::Strategy)> {
+        use super::positions::{cells_in_block, cells_in_col, cells_in_row};
+        use crate::board::Digit;
+        use crate::strategy::Strategy;
+
+        let candidates = self.cell_candidates();
+        let mut forced: Vec<Option<(u8, Strategy)>> = vec![None; N_CELLS];
+        for (cell, candidates) in candidates.iter().enumerate() {
+            if let Ok(Some(digit)) = candidates.unique() {
+                forced[cell] = Some((digit.get(), Strategy::NakedSingles));
+            }
+        }
+        let houses = (0..9)
+            .map(cells_in_row)
+            .chain((0..9).map(cells_in_col))
+            .chain((0..9).map(cells_in_block));
+        for house in houses {
+            for digit in (1..=9).map(Digit::new) {
+                if house.iter().any(|&cell| self.0[cell as usize] == digit.get()) {
+                    continue;
+                }
+                let mut cells = house
+                    .iter()
+                    .filter(|&&cell| candidates[cell as usize].contains(digit));
+                if let (Some(&cell), None) = (cells.next(), cells.next()) {
+                    let entry = &mut forced[cell as usize];
+                    if entry.is_none() {
+                        *entry = Some((digit.get(), Strategy::HiddenSingles));
+                    }
+                }
+            }
+        }
+        (0..)
+            .zip(forced)
+            .filter_map(|(cell, entry)| entry.map(|(digit, strategy)| (cell, digit, strategy)))
+            .collect()
+    }
+
+    /// Returns for each empty cell the index of the deduction by which it was filled in, when
+    /// solving the sudoku logically.
+    ///
+    /// The strategies are tried with the easiest first, so cells with lower indices tend to be easier to find.
+    /// Cells that can't be filled in without guessing and the clues are `None`.
+    /// Strategies that assume a unique solution are not used.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let order = sudoku.cell_solve_order();
+    ///
+    /// assert_eq!(order[3], None);
+    /// assert_eq!(order.iter().filter(|step| step.is_some()).count(), sudoku.n_empty() as usize);
+    /// ```
+    pub fn cell_solve_order(&self) -> [Option<u16>; N_CELLS] {
+        use crate::strategy::{Deduction, StrategySolver};
+
+        let mut order = [None; N_CELLS];
+        let deductions = match StrategySolver::from_sudoku(*self).solve(&strategies_without_uniqueness()) {
+            Ok((_, deductions)) | Err((_, deductions)) => deductions,
+        };
+        for (step, deduction) in (0..).zip(deductions.iter()) {
+            let cell = match deduction {
+                Deduction::NakedSingles(candidate) | Deduction::HiddenSingles(candidate, _) => candidate.cell,
+                _ => continue,
+            };
+            let entry = &mut order[cell.as_index()];
+            if entry.is_none() && self.0[cell.as_index()] == 0 {
+                *entry = Some(step);
+            }
+        }
+        order
+    }
+
+    /// Solve the sudoku and count how many of its empty cells can be filled in by naked singles,
+    /// by hidden singles and by guessing. See [`SolveStats`] for details.
+    /// Returns `None`, if the sudoku has no or multiple solutions.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let (solution, stats) = sudoku.solve_with_stats().unwrap();
+    ///
+    /// assert_eq!(Some(solution), sudoku.solution());
+    /// assert_eq!(
+    ///     stats.singles_placed + stats.hidden_singles_placed + stats.guessed_placed,
+    ///     sudoku.n_empty()
+    /// );
+    /// ```
+    pub fn solve_with_stats(self) -> Option<(Sudoku, SolveStats)> {
+        crate::strategy::stats::solve_with_stats(self)
+    }
+
+    /// Grade the sudoku both with the strategies of [`Difficulty::of`] and by the number of guesses
+    /// of the backtracking solver. See [`RatingReport`] for how the two are related.
+    /// Returns `None`, if the sudoku doesn't have a unique solution.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let report = sudoku.rating_report().unwrap();
+    /// assert!(report.is_consistent());
+    /// ```
+    pub fn rating_report(self) -> Option<RatingReport> {
+        crate::strategy::rating::rating_report(self)
+    }
+
+    /// Solve the sudoku and return its solution together with evidence that there is no other solution.
+    /// See [`UniquenessProof`] for the contents of the proof.
+    /// Returns `None`, if the sudoku has no or multiple solutions.
+    ///
+    /// The whole search tree has to be recorded, so this is slower than [`Sudoku::solution`].
+    pub fn uniqueness_proof(self) -> Option<UniquenessProof> {
+        SudokuSolver::from_sudoku(self)
+            .ok()
+            .and_then(SudokuSolver::uniqueness_proof)
+    }
+
+    /// Find a solution like [`Sudoku::some_solution`] and count the nodes of the solver's search tree at each depth.
+    /// See [`TreeStats`] for how the tree is built.
+    ///
+    /// This is a diagnostic for seeing how much guessing a sudoku requires. The search is slower than
+    /// with [`Sudoku::some_solution`], which doesn't keep track of the depth.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let (solution, stats) = sudoku.solve_with_tree_stats();
+    /// assert_eq!(solution, sudoku.solution());
+    /// assert_eq!(stats.nodes_per_depth()[0], 1);
+    /// ```
+    pub fn solve_with_tree_stats(self) -> (Option<Sudoku>, TreeStats) {
+        SudokuSolver::solve_with_tree_stats(SudokuSolver::from_sudoku(self))
+    }
+
+    /// Start solving the sudoku one step at a time. See [`SolveSession`] for details.
+    ///
+    /// This is meant for showing the solving process, e.g. in an animation. The session ends at the first
+    /// solution, like [`Sudoku::some_solution`].
+    pub fn solve_session(self) -> SolveSession {
+        SolveSession::new(self)
+    }
+
+    /// Find up to `limit` solutions and call `f` with each one as soon as it's found.
+    /// The search stops early, if `f` returns `false`. Returns the number of solutions `f` was called with.
+    ///
+    /// Unlike [`Sudoku::solutions_up_to`], this doesn't store the solutions.
+    /// No specific ordering of solutions is promised. It can change across versions.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    /// let mut first_cells = vec![];
+    /// let n_solutions = empty.for_each_solution(100, |solution| {
+    ///     first_cells.push(solution.to_bytes()[0]);
+    ///     first_cells.len() < 10
+    /// });
+    ///
+    /// assert_eq!(n_solutions, 10);
+    /// assert_eq!(first_cells.len(), 10);
+    /// ```
+    pub fn for_each_solution<F: FnMut(&Sudoku) -> bool>(self, limit: usize, mut f: F) -> usize {
+        SudokuSolver::from_sudoku(self).map_or(0, |solver| solver.for_each_solution(limit, &mut f))
+    }
+
+    /// Returns the solution with index `n` in the order the solver finds them, or `None`, if the sudoku
+    /// has `n` or fewer solutions.
+    ///
+    /// This is the same as `self.solutions_up_to(n + 1).get(n)`, but the other solutions aren't stored.
+    /// The order of the solutions is unspecified, but doesn't change between runs.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    /// assert_eq!(empty.nth_solution(2), Some(empty.solutions_up_to(3)[2]));
+    /// ```
+    pub fn nth_solution(self, n: usize) -> Option<Sudoku> {
+        SudokuSolver::from_sudoku(self)
+            .ok()
+            .and_then(|solver| solver.nth_solution(n))
+    }
+
     /// Counts number of solutions to sudoku up to `limit`.
     /// This solves the sudoku but does not return the solutions which allows for slightly faster execution.
     pub fn solutions_count_up_to(self, limit: usize) -> usize {
@@ -596,48 +1802,842 @@ impl Sudoku {
         self.solutions_count_up_to(2) == 1
     }
 
+    /// Checks whether sudoku has at least 2 solutions. Equivalent to `self.solutions_count_up_to(2) == 2`.
+    ///
+    /// Sudokus with fewer than 17 clues or fewer than 8 different digits can never be unique.
+    /// For those, it suffices to find any solution.
+    pub fn has_multiple_solutions(self) -> bool {
+        let mut digits_present = [false; 10];
+        for &num in self.0.iter() {
+            digits_present[num as usize] = true;
+        }
+        let n_digits = digits_present[1..].iter().filter(|&&present| present).count();
+        if self.n_clues() < 17 || n_digits < 8 {
+            return self.some_solution().is_some();
+        }
+        self.solutions_count_up_to(2) == 2
+    }
+
     /// Solve sudoku and return the first `limit` solutions it finds. If less solutions exist, return only those. Return `None` if no solution exists.
     /// No specific ordering of solutions is promised. It can change across versions.
+    /// See [`Sudoku::smallest_solutions`] for a deterministic order.
     pub fn solutions_up_to(self, limit: usize) -> Vec<Sudoku> {
         SudokuSolver::from_sudoku(self)
             .ok()
             .map_or(vec![], |solver| solver.solutions_up_to(limit))
     }
 
-    /// Counts number of solutions to sudoku up to `limit` and writes any solution found into `target`
-    /// up to its capacity. Additional solutions will be counted but not saved.
-    /// No specific ordering of solutions is promised. It can change across versions.
-    /// This is primarily meant for C FFI.
-    pub fn solutions_up_to_buffer(self, target: &mut [SudokuArray], limit: usize) -> usize {
-        SudokuSolver::from_sudoku(self)
-            .ok()
-            .map_or(0, |solver| solver.solutions_up_to_buffer(target, limit))
+    /// Counts the solutions of the sudoku by the digit they contain in `cell`.
+    ///
+    /// `counts[digit]` is the number of solutions with `digit` in `cell`. Index 0 is always 0, because
+    /// solutions have no empty cells. The digits are counted one after another until a total of
+    /// `limit` solutions has been reached, so if the sudoku has more solutions, the counts of the
+    /// larger digits are cut off.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    /// assert_eq!(empty.solution_distribution(0, 20), [0, 20, 0, 0, 0, 0, 0, 0, 0, 0]);
+    ///
+    /// let sudoku = Sudoku::generate();
+    /// let solution = sudoku.solution().unwrap();
+    /// let counts = sudoku.solution_distribution(0, 100);
+    /// assert_eq!(counts.iter().sum::<usize>(), 1);
+    /// assert_eq!(counts[solution.to_bytes()[0] as usize], 1);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn solution_distribution(&self, cell: u8, limit: usize) -> [usize; 10] {
+        assert!((cell as usize) < N_CELLS, "cell index out of range: {}", cell);
+        let mut counts = [0; 10];
+        let mut remaining = limit;
+        for digit in 1..=9 {
+            if remaining == 0 {
+                break;
+            }
+            let mut grid = self.0;
+            match grid[cell as usize] {
+                0 => grid[cell as usize] = digit,
+                filled if filled != digit => continue,
+                _ => {}
+            }
+            counts[digit as usize] = Sudoku(grid).solutions_count_up_to(remaining);
+            remaining -= counts[digit as usize];
+        }
+        counts
+    }
+
+    /// Solve sudoku and return the first `limit` solutions it finds, like [`Sudoku::solutions_up_to`],
+    /// but abort the search with an error after visiting `max_nodes` nodes of the search tree.
+    ///
+    /// The initial state and every guess count as one node each. Most sudokus need only a few
+    /// dozen nodes, but specially crafted ones can take much longer to solve.
+    /// This allows bounding the time spent on untrusted input.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_bytes([0; 81]).unwrap();
+    ///
+    /// assert!(sudoku.solutions_up_to_with_budget(1000, 10).is_err());
+    /// assert_eq!(sudoku.solutions_up_to_with_budget(2, 1000).unwrap().len(), 2);
+    /// ```
+    pub fn solutions_up_to_with_budget(self, limit: usize, max_nodes: u64) -> Result<Vec<Sudoku>, Timeout> {
+        match SudokuSolver::from_sudoku(self) {
+            Ok(solver) => solver
+                .solutions_up_to_with_budget(limit, max_nodes)
+                .ok_or(Timeout { max_nodes }),
+            Err(_) => Ok(vec![]),
+        }
+    }
+
+    /// Solve sudoku and return the first `limit` solutions it finds, like [`Sudoku::solutions_up_to`],
+    /// but with only the enabled deduction `passes`. No specific ordering of solutions is promised.
+    ///
+    /// This is meant for benchmarking how much each pass contributes to the solving speed.
+    /// See [`Passes`] for the passes that can be turned off.
+    ///
+    /// ```
+    /// use sudoku::solver::Passes;
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(sudoku.solutions_up_to_with_passes(2, Passes::NONE), sudoku.solutions_up_to(2));
+    /// ```
+    pub fn solutions_up_to_with_passes(self, limit: usize, passes: Passes) -> Vec<Sudoku> {
+        SudokuSolver::from_sudoku(self)
+            .ok()
+            .map_or(vec![], |solver| solver.solutions_up_to_with_passes(limit, passes))
+    }
+
+    /// Solve sudoku and return the first `limit` solutions it finds, like [`Sudoku::solutions_up_to`],
+    /// but with a custom heuristic for choosing the cells to guess on.
+    /// No specific ordering of solutions is promised.
+    ///
+    /// See the [`solver`](crate::solver) module for details.
+    pub fn solutions_up_to_with_guess_strategy(
+        self,
+        limit: usize,
+        guess_strategy: &mut impl GuessStrategy,
+    ) -> Vec<Sudoku> {
+        SudokuSolver::from_sudoku(self).ok().map_or(vec![], |solver| {
+            solver.solutions_up_to_with_guess_strategy(limit, guess_strategy)
+        })
+    }
+
+    /// Counts number of solutions to sudoku up to `limit` and writes any solution found into `target`
+    /// up to its capacity. Additional solutions will be counted but not saved.
+    /// No specific ordering of solutions is promised. It can change across versions.
+    /// This is primarily meant for C FFI.
+    pub fn solutions_up_to_buffer(self, target: &mut [SudokuArray], limit: usize) -> usize {
+        SudokuSolver::from_sudoku(self)
+            .ok()
+            .map_or(0, |solver| solver.solutions_up_to_buffer(target, limit))
+    }
+
+    /// Check whether the sudoku is solved.
+    //
+    // iterates through all cells and checks for each row, col and block
+    // if all 9 digits are present
+    pub fn is_solved(&self) -> bool {
+        use crate::bitset::Set;
+        use crate::board::*;
+        use crate::helper::HouseArray;
+
+        // collection of digit sets for all 9 rows, 9 cols and 9 blocks
+        let mut house_digits = HouseArray([Set::NONE; N_HOUSES]);
+
+        for (cell, &content) in Cell::all().zip(self.0.iter()) {
+            let digit = match Digit::new_checked(content) {
+                None => return false,
+                Some(digit) => digit.as_set(),
+            };
+
+            house_digits[cell.row()] |= digit;
+            house_digits[cell.col()] |= digit;
+            house_digits[cell.block()] |= digit;
+        }
+
+        house_digits == HouseArray([Set::ALL; N_HOUSES])
+    }
+
+    /// Combines the filled cells of both sudokus.
+    ///
+    /// Cells that are filled in only one of them are filled in the result.
+    /// Returns an error for the first cell that contains different digits in both sudokus.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut top = [0; 81];
+    /// top[0] = 1;
+    /// let mut bottom = [0; 81];
+    /// bottom[80] = 9;
+    ///
+    /// let top = Sudoku::from_bytes(top).unwrap();
+    /// let bottom = Sudoku::from_bytes(bottom).unwrap();
+    /// let combined = top.overlay(&bottom).unwrap();
+    /// assert_eq!(combined.n_clues(), 2);
+    /// ```
+    pub fn overlay(&self, other: &Sudoku) -> Result<Sudoku, OverlayError> {
+        let mut grid = self.0;
+        for (cell, (digit, &other_digit)) in grid.iter_mut().zip(other.0.iter()).enumerate() {
+            match (*digit, other_digit) {
+                (_, 0) => {}
+                (0, _) => *digit = other_digit,
+                (digit, other_digit) if digit != other_digit => {
+                    return Err(OverlayError {
+                        cell: cell as u8,
+                        digit,
+                        other_digit,
+                    })
+                }
+                _ => {}
+            }
+        }
+        Ok(Sudoku(grid))
+    }
+
+    /// Checks whether `answer` is a correct solution of `puzzle`.
+    ///
+    /// The checks are made in the following order and the first failing one is reported:
+    /// 1. every clue of `puzzle` is kept unchanged in `answer`
+    /// 2. no entry of `answer` conflicts with another one in its row, column or block
+    /// 3. `answer` is completely filled
+    ///
+    /// An answer passing all checks is [`Correct`](AnswerResult::Correct). It is not compared against
+    /// the solution of `puzzle`, so for puzzles with multiple solutions every one of them is accepted.
+    ///
+    /// ```
+    /// use sudoku::{board::AnswerResult, Sudoku};
+    ///
+    /// let puzzle = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let solution = puzzle.solution().unwrap();
+    ///
+    /// assert_eq!(Sudoku::check_answer(&puzzle, &solution), AnswerResult::Correct);
+    /// let mut answer = solution.to_bytes();
+    /// answer[0] = 0;
+    /// let answer = Sudoku::from_bytes(answer).unwrap();
+    ///
+    /// assert_eq!(Sudoku::check_answer(&puzzle, &answer), AnswerResult::Incomplete(vec![0]));
+    /// ```
+    pub fn check_answer(puzzle: &Sudoku, answer: &Sudoku) -> AnswerResult {
+        let changed_clues: Vec<u8> = (0..N_CELLS as u8)
+            .filter(|&cell| {
+                let clue = puzzle.0[cell as usize];
+                clue != 0 && answer.0[cell as usize] != clue
+            })
+            .collect();
+        if !changed_clues.is_empty() {
+            return AnswerResult::ClueChanged(changed_clues);
+        }
+
+        let conflicts = answer.conflicts();
+        if !conflicts.is_empty() {
+            return AnswerResult::Invalid(conflicts);
+        }
+
+        let empty_cells: Vec<u8> = (0..N_CELLS as u8)
+            .filter(|&cell| answer.0[cell as usize] == 0)
+            .collect();
+        if !empty_cells.is_empty() {
+            return AnswerResult::Incomplete(empty_cells);
+        }
+        AnswerResult::Correct
+    }
+
+    /// Returns the filled cells whose entry is also contained in another cell of the same
+    /// row, column or block, in ascending order.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut grid = [0; 81];
+    /// grid[0] = 1;
+    /// grid[10] = 1;
+    /// grid[80] = 1;
+    /// let sudoku = Sudoku::from_bytes(grid).unwrap();
+    ///
+    /// assert_eq!(sudoku.conflicts(), vec![0, 10]);
+    /// ```
+    pub fn conflicts(&self) -> Vec<u8> {
+        (0..N_CELLS as u8)
+            .filter(|&cell| {
+                let num = self.0[cell as usize];
+                num != 0
+                    && super::positions::neighbors(cell)
+                        .iter()
+                        .any(|&peer| self.0[peer as usize] == num)
+            })
+            .collect()
+    }
+
+    /// Enters `digit` into the empty `cell`, if no cell in the same row, column or block contains it already.
+    ///
+    /// Otherwise, the sudoku is left unchanged and the reason is returned. For conflicts, the first
+    /// neighboring cell containing `digit` is reported.
+    /// This doesn't check whether the sudoku is still solvable after the placement.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    /// use sudoku::errors::PlacementError;
+    ///
+    /// let mut sudoku = Sudoku::from_bytes([0; 81]).unwrap();
+    /// sudoku.try_place(0, 5).unwrap();
+    ///
+    /// assert_eq!(sudoku.try_place(80, 5), Ok(()));
+    /// assert_eq!(sudoku.try_place(8, 5), Err(PlacementError::Conflict { digit: 5, peer: 0 }));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn try_place(&mut self, cell: u8, digit: u8) -> Result<(), PlacementError> {
+        assert!((cell as usize) < N_CELLS, "cell index out of range: {}", cell);
+        if !(1..=9).contains(&digit) {
+            return Err(PlacementError::InvalidDigit(digit));
+        }
+        match self.0[cell as usize] {
+            0 => {}
+            filled => return Err(PlacementError::CellFilled { cell, digit: filled }),
+        }
+        if let Some(&peer) = super::positions::neighbors(cell)
+            .iter()
+            .find(|&&peer| self.0[peer as usize] == digit)
+        {
+            return Err(PlacementError::Conflict { digit, peer });
+        }
+        self.0[cell as usize] = digit;
+        Ok(())
+    }
+
+    /// Empties all `cells`. Cells that are already empty stay empty.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut sudoku = Sudoku::generate_solved();
+    /// sudoku.remove_clues(&[0, 40, 80]);
+    /// assert_eq!(sudoku.n_empty(), 3);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics, if any cell is outside of `0..81`. The sudoku is left unchanged in that case.
+    pub fn remove_clues(&mut self, cells: &[u8]) {
+        if let Some(&cell) = cells.iter().find(|&&cell| cell as usize >= N_CELLS) {
+            panic!("cell index out of range: {}", cell);
+        }
+        for &cell in cells {
+            self.0[cell as usize] = 0;
+        }
+    }
+
+    /// Fills the empty cells of this sudoku with the entries of `solution`. Filled cells are never
+    /// changed.
+    ///
+    /// Returns an error for the first cell whose entry differs from the one in `solution`.
+    /// In that case, the sudoku is left unchanged. This is the in-place version of [`Sudoku::overlay`].
+    pub fn fill_in(&mut self, solution: &Sudoku) -> Result<(), OverlayError> {
+        *self = self.overlay(solution)?;
+        Ok(())
+    }
+
+    /// Returns which cells are filled, going from left to right, top to bottom.
+    ///
+    /// Solving a sudoku fills all cells, so the mask of the clues
+    /// must be taken from the puzzle before solving it.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let clues = sudoku.clues_mask();
+    /// let solution = sudoku.solution().unwrap();
+    ///
+    /// assert!(!clues[0] && clues[3]);
+    /// assert!(solution.clues_mask().iter().all(|&filled| filled));
+    /// ```
+    pub fn clues_mask(&self) -> [bool; N_CELLS] {
+        let mut mask = [false; N_CELLS];
+        for (is_clue, &num) in mask.iter_mut().zip(self.0.iter()) {
+            *is_clue = num != 0;
+        }
+        mask
+    }
+
+    /// Checks whether every filled cell of `self` contains the same digit in `other`.
+    ///
+    /// Empty cells of `self` are ignored, so a puzzle is consistent with each of its solutions
+    /// and with any partially filled answer that keeps its clues. Unlike `==`, this isn't symmetric.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let puzzle = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let solution = puzzle.solution().unwrap();
+    ///
+    /// assert!(puzzle.is_consistent_with(&solution));
+    /// assert!(!solution.is_consistent_with(&puzzle));
+    /// ```
+    pub fn is_consistent_with(&self, other: &Sudoku) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(&num, &other_num)| num == 0 || num == other_num)
+    }
+
+    /// Checks whether `self` is the one and only solution of `puzzle`.
+    ///
+    /// That is the case, if `self` is [solved](Sudoku::is_solved), keeps all clues of `puzzle`
+    /// (see [`Sudoku::is_consistent_with`]) and `puzzle` has no other solution. The cheap checks are
+    /// done first, so the puzzle is only solved if `self` is one of its solutions.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let puzzle = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let solution = puzzle.solution().unwrap();
+    ///
+    /// assert!(solution.is_unique_solution_of(&puzzle));
+    /// assert!(!puzzle.is_unique_solution_of(&puzzle));
+    /// ```
+    pub fn is_unique_solution_of(&self, puzzle: &Sudoku) -> bool {
+        self.is_solved() && puzzle.is_consistent_with(self) && puzzle.solutions_count_up_to(2) == 1
+    }
+
+    /// Returns the single clues that would make a puzzle with multiple solutions uniquely solvable,
+    /// as `(cell, digit)` pairs in ascending order. At most `limit` clues are returned.
+    ///
+    /// Every candidate of every empty cell (see [`Sudoku::candidate_counts`]) is tried as an additional clue,
+    /// which takes one uniqueness check each. This is expensive for puzzles with few clues, but bounded by
+    /// the number of candidates. If the sudoku has no or only one solution, no clues are returned.
+    ///
+    /// ```
+    /// use sudoku::{Sudoku, Symmetry};
+    ///
+    /// // all clues of a minimal puzzle are necessary for uniqueness
+    /// let mut puzzle = Sudoku::generate_with_symmetry(Symmetry::None);
+    /// let mut bytes = puzzle.to_bytes();
+    /// let cell = bytes.iter().position(|&num| num != 0).unwrap();
+    /// let digit = std::mem::replace(&mut bytes[cell], 0);
+    /// let ambiguous = Sudoku::from_bytes(bytes).unwrap();
+    ///
+    /// let clues = ambiguous.completing_clues(81 * 9);
+    /// assert!(clues.contains(&(cell as u8, digit)));
+    /// assert!(puzzle.completing_clues(81 * 9).is_empty());
+    /// ```
+    pub fn completing_clues(&self, limit: usize) -> Vec<(u8, u8)> {
+        let mut clues = vec![];
+        if limit == 0 || self.solutions_count_up_to(2) != 2 {
+            return clues;
+        }
+        let candidates = self.cell_candidates();
+        for (cell, candidates) in (0..).zip(candidates.iter()) {
+            for digit in candidates.into_iter() {
+                let mut sudoku = *self;
+                sudoku.0[cell as usize] = digit.get();
+                if sudoku.is_uniquely_solvable() {
+                    clues.push((cell, digit.get()));
+                    if clues.len() == limit {
+                        return clues;
+                    }
+                }
+            }
+        }
+        clues
+    }
+
+    /// Returns the number of digits that could be entered into each cell without
+    /// conflicting with the filled cells in the same row, column or block.
+    ///
+    /// Filled cells are reported as having 0 candidates, regardless of whether their
+    /// entry conflicts with other entries. Only direct conflicts with filled cells are considered,
+    /// no further deductions are made.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let counts = sudoku.candidate_counts();
+    ///
+    /// assert_eq!(counts[3], 0);
+    /// assert!((1..=9).contains(&counts[0]));
+    /// ```
+    pub fn candidate_counts(&self) -> [u8; N_CELLS] {
+        let mut counts = [0; N_CELLS];
+        for (count, candidates) in counts.iter_mut().zip(self.cell_candidates().iter()) {
+            *count = candidates.len();
+        }
+        counts
+    }
+
+    /// Returns the digits that could be entered into each cell without conflicting with the filled cells
+    /// in the same row, column or block, in ascending order. Filled cells get an empty list.
+    ///
+    /// This contains the same candidates as [`Sudoku::candidate_counts`] counts, as plain numbers
+    /// for consumers that don't want to deal with bitsets.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut grid = [0; 81];
+    /// grid[1..9].copy_from_slice(&[2, 3, 4, 5, 6, 7, 8, 9]);
+    /// let lists = Sudoku::from_bytes(grid).unwrap().candidate_lists();
+    ///
+    /// assert_eq!(lists.len(), 81);
+    /// assert_eq!(lists[0], vec![1]);
+    /// assert!(lists[1].is_empty());
+    /// assert_eq!(lists[80], vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    pub fn candidate_lists(&self) -> Vec<Vec<u8>> {
+        self.cell_candidates()
+            .iter()
+            .map(|candidates| candidates.into_iter().map(|digit| digit.get()).collect())
+            .collect()
+    }
+
+    /// Returns the candidates of every cell that would result from entering `num` into `cell`, without changing the sudoku.
+    /// Each set of candidates is given as a bitmask in the layout of [`Set::bits`](crate::bitset::Set::bits),
+    /// i.e. bit `d - 1` stands for digit `d`. Filled cells, including `cell`, have no candidates.
+    ///
+    /// The entry is propagated by the same incremental candidate updates the strategy solver uses.
+    /// Returns `None`, if the entry conflicts with the filled cells or leaves an empty cell without candidates.
+    /// Entering the digit a cell already contains changes nothing, entering another one is a conflict.
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81` or `num` is not in `1..=9`.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut grid = [0; 81];
+    /// grid[1..9].copy_from_slice(&[2, 3, 4, 5, 6, 7, 8, 9]);
+    /// let sudoku = Sudoku::from_bytes(grid).unwrap();
+    ///
+    /// let candidates = sudoku.candidates_after_placement(0, 1).unwrap();
+    /// assert_eq!(candidates[0], 0);
+    /// assert_eq!(candidates[9], 0b1_1111_1000);
+    /// assert_eq!(candidates[80], 0b0_1111_1111);
+    ///
+    /// // cell 0 would have no candidates left
+    /// assert_eq!(sudoku.candidates_after_placement(9, 1), None);
+    /// // conflicts with the 2 in cell 1
+    /// assert_eq!(sudoku.candidates_after_placement(9, 2), None);
+    /// ```
+    pub fn candidates_after_placement(&self, cell: u8, num: u8) -> Option<[u16; N_CELLS]> {
+        use crate::board::{Candidate, CellState};
+        use crate::strategy::StrategySolver;
+
+        let mut solver = StrategySolver::from_sudoku(*self);
+        solver.insert_candidate(Candidate::new(cell, num)).ok()?;
+        // finds conflicts and cells without candidates
+        solver._update_cell_poss_house_solved(false, true).ok()?;
+
+        let mut candidates = [0; N_CELLS];
+        for (mask, cell_state) in candidates.iter_mut().zip(solver.grid_state().iter()) {
+            if let CellState::Candidates(digits) = cell_state {
+                *mask = digits.bits();
+            }
+        }
+        Some(candidates)
+    }
+
+    /// Returns the total number of candidates of all empty cells, i.e. the sum of [`Sudoku::candidate_counts`].
+    ///
+    /// Lower totals mean that the sudoku is more constrained, which is a rough hint that it's easier.
+    /// The candidates only reflect a single round of eliminating the digits of the filled cells
+    /// from their neighbors. No further deductions are made, so this is much cheaper than solving.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// assert_eq!(Sudoku::from_bytes([0; 81]).unwrap().total_candidates(), 81 * 9);
+    /// assert_eq!(Sudoku::generate_solved().total_candidates(), 0);
+    /// ```
+    pub fn total_candidates(&self) -> u32 {
+        self.cell_candidates()
+            .iter()
+            .map(|candidates| candidates.len() as u32)
+            .sum()
+    }
+
+    /// Returns the empty cell with the fewest candidates together with the number of its candidates,
+    /// or `None`, if all cells are filled. Of several cells with the same number of candidates, the first one is returned.
+    ///
+    /// This is the cell that is usually best for guessing or looking at next. A count of 0 means that
+    /// the sudoku is unsolvable. The candidates are the same as for [`Sudoku::candidate_counts`].
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let (cell, n_candidates) = sudoku.most_constrained_cell().unwrap();
+    ///
+    /// let counts = sudoku.candidate_counts();
+    /// assert_eq!(counts[cell as usize], n_candidates);
+    /// assert!(sudoku.iter().zip(counts.iter()).all(|(num, &count)| num.is_some() || count >= n_candidates));
+    /// assert_eq!(Sudoku::generate_solved().most_constrained_cell(), None);
+    /// ```
+    pub fn most_constrained_cell(&self) -> Option<(u8, u8)> {
+        let candidates = self.cell_candidates();
+        (0..N_CELLS as u8)
+            .filter(|&cell| self.0[cell as usize] == 0)
+            .map(|cell| (cell, candidates[cell as usize].len()))
+            .min_by_key(|&(_, n_candidates)| n_candidates)
+    }
+
+    /// Returns a band, stack, block, row or column without any clues, or `None`, if every one of them contains a clue.
+    ///
+    /// Larger units are checked first, so that an empty band or stack is reported instead of the
+    /// empty rows, columns and blocks within it. Of units of the same kind, the first one is returned.
+    /// Puzzles with empty units are often harder than others with the same number of clues.
+    ///
+    /// ```
+    /// use sudoku::board::positions::{Band, Zone};
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut bytes = Sudoku::generate_solved().to_bytes();
+    /// for num in &mut bytes[27..54] {
+    ///     *num = 0;
+    /// }
+    /// let sudoku = Sudoku::from_bytes(bytes).unwrap();
+    ///
+    /// assert_eq!(sudoku.has_empty_unit(), Some(Zone::Band(Band::new(1))));
+    /// assert_eq!(Sudoku::generate_solved().has_empty_unit(), None);
+    /// ```
+    pub fn has_empty_unit(&self) -> Option<crate::board::positions::Zone> {
+        use crate::board::positions::*;
+
+        let mut n_clues_per_house = [0; N_HOUSES];
+        for (cell, _) in (0..).zip(self.0.iter()).filter(|&(_, &num)| num != 0) {
+            n_clues_per_house[row(cell) as usize] += 1;
+            n_clues_per_house[(COL_OFFSET + col(cell)) as usize] += 1;
+            n_clues_per_house[(BLOCK_OFFSET + block(cell)) as usize] += 1;
+        }
+        let is_empty = |house: u8| n_clues_per_house[house as usize] == 0;
+        let are_empty = |first: u8| (first..first + 3).all(is_empty);
+
+        (0..3)
+            .find(|&band| are_empty(band * 3))
+            .map(|band| Zone::Band(Band::new(band)))
+            .or_else(|| {
+                (0..3)
+                    .find(|&stack| are_empty(COL_OFFSET + stack * 3))
+                    .map(|stack| Zone::Stack(Stack::new(stack)))
+            })
+            .or_else(|| {
+                (0..9)
+                    .find(|&block| is_empty(BLOCK_OFFSET + block))
+                    .map(|block| Zone::Block(Block::new(block)))
+            })
+            .or_else(|| {
+                (0..9)
+                    .find(|&row| is_empty(row))
+                    .map(|row| Zone::Row(Row::new(row)))
+            })
+            .or_else(|| {
+                (0..9)
+                    .find(|&col| is_empty(COL_OFFSET + col))
+                    .map(|col| Zone::Col(Col::new(col)))
+            })
+    }
+
+    /// Checks whether the empty cells contain the geometry of a deadly pattern: 4 cells at the corners of a
+    /// rectangle spanning 2 rows, 2 columns and 2 blocks, which have at least 2 candidates in common.
+    ///
+    /// If 2 of these digits end up in the rectangle, they can be swapped without breaking any rule,
+    /// so the sudoku has multiple solutions, unless the other cells rule one of the arrangements out.
+    /// A `true` result is therefore only a hint that a full uniqueness check like [`Sudoku::is_uniquely_solvable`]
+    /// is worthwhile. Only the smallest kind of unavoidable set is detected, so `false` doesn't guarantee
+    /// a unique solution either. The candidates are the same as for [`Sudoku::candidate_counts`].
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// assert!(Sudoku::from_bytes([0; 81]).unwrap().has_deadly_pattern_risk());
+    /// assert!(!Sudoku::generate_solved().has_deadly_pattern_risk());
+    /// ```
+    pub fn has_deadly_pattern_risk(&self) -> bool {
+        let candidates = self.cell_candidates();
+        let candidates = |row: usize, col: usize| candidates[row * 9 + col];
+        for row1 in 0..9 {
+            for row2 in row1 + 1..9 {
+                for col1 in 0..9 {
+                    for col2 in col1 + 1..9 {
+                        // exactly 2 blocks, i.e. either the rows or the columns are in the same chute
+                        if (row1 / 3 == row2 / 3) == (col1 / 3 == col2 / 3) {
+                            continue;
+                        }
+                        let common = candidates(row1, col1)
+                            & candidates(row1, col2)
+                            & candidates(row2, col1)
+                            & candidates(row2, col2);
+                        if common.len() >= 2 {
+                            return true;
+                        }
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// Returns the digits that could be entered into `cell` without conflicting with the filled
+    /// cells in the same row, column or block.
+    ///
+    /// Only the neighbors of `cell` are looked at, so this is cheaper than
+    /// [`Sudoku::candidate_counts`] when only a single cell is of interest.
+    /// Filled cells have no candidates.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let candidates = sudoku.candidates_for(0);
+    ///
+    /// assert!(sudoku.candidates_for(3).is_empty());
+    /// assert_eq!(candidates.len(), sudoku.candidate_counts()[0]);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn candidates_for(&self, cell: u8) -> crate::bitset::Set<crate::board::Digit> {
+        use crate::bitset::Set;
+        use crate::board::Digit;
+
+        assert!((cell as usize) < N_CELLS, "cell index out of range: {}", cell);
+        if self.0[cell as usize] != 0 {
+            return Set::NONE;
+        }
+        super::positions::neighbors(cell)
+            .iter()
+            .filter_map(|&peer| Digit::new_checked(self.0[peer as usize]))
+            .fold(Set::ALL, |candidates, digit| candidates.without(digit.as_set()))
+    }
+
+    /// Returns the indices of all empty cells in which `digit` could be entered without
+    /// conflicting with the filled cells in the same row, column or block, in ascending order.
+    ///
+    /// The candidates are the same as for [`Sudoku::candidate_counts`].
+    ///
+    /// # Panics
+    /// Panics, if `digit` is not in range 1..=9.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let cells = sudoku.cells_with_candidate(2);
+    ///
+    /// assert!(!cells.contains(&3)); // the 2 itself
+    /// assert!(!cells.contains(&0)); // same row as the 2
+    /// ```
+    pub fn cells_with_candidate(&self, digit: u8) -> Vec<u8> {
+        assert!((1..=9).contains(&digit), "digit must be in range 1..=9");
+        let digit = crate::board::Digit::new(digit);
+        (0..N_CELLS as u8)
+            .zip(self.cell_candidates().iter())
+            .filter(|(_, candidates)| candidates.contains(digit))
+            .map(|(cell, _)| cell)
+            .collect()
+    }
+
+    /// Returns a cheap, rough estimate of how hard the sudoku is to solve. Higher values are harder.
+    ///
+    /// No solving is attempted. The estimate only looks at the candidates of each cell given the
+    /// filled cells (see [`Sudoku::candidate_counts`]):
+    /// - the number of surplus candidates, i.e. candidates beyond the first, of all empty cells
+    /// - plus 9 for every row, column and block with less than 2 clues
+    /// - divided by 1 + the number of cells that can be filled immediately as a naked single
+    ///
+    /// This takes about as long as checking all cells once, so it can be used as a pre-filter before
+    /// a more expensive rating. It is only a heuristic, exact values may change between versions.
+    /// A completely filled sudoku has an estimate of 0.
+    pub fn complexity_estimate(&self) -> u32 {
+        use crate::board::*;
+        use crate::helper::HouseArray;
+
+        let mut n_house_clues = HouseArray([0; N_HOUSES]);
+        for (cell, _) in Cell::all().zip(self.0.iter()).filter(|(_, &num)| num != 0) {
+            for &house in cell.houses().iter() {
+                n_house_clues[house] += 1;
+            }
+        }
+        let n_sparse_houses = n_house_clues.0.iter().filter(|&&n| n < 2).count() as u32;
+
+        let mut n_surplus_candidates = 0;
+        let mut n_naked_singles = 0;
+        for (candidates, _) in self
+            .cell_candidates()
+            .iter()
+            .zip(self.0.iter())
+            .filter(|(_, &num)| num == 0)
+        {
+            match candidates.len() {
+                0 => {}
+                1 => n_naked_singles += 1,
+                n => n_surplus_candidates += n as u32 - 1,
+            }
+        }
+
+        (n_surplus_candidates + 9 * n_sparse_houses) / (1 + n_naked_singles)
     }
 
-    /// Check whether the sudoku is solved.
-    //
-    // iterates through all cells and checks for each row, col and block
-    // if all 9 digits are present
-    pub fn is_solved(&self) -> bool {
+    // For every cell, the digits that don't conflict with the filled cells among its neighbors.
+    // Filled cells have no candidates.
+    pub(crate) fn cell_candidates(&self) -> [crate::bitset::Set<crate::board::Digit>; N_CELLS] {
         use crate::bitset::Set;
         use crate::board::*;
         use crate::helper::HouseArray;
 
-        // collection of digit sets for all 9 rows, 9 cols and 9 blocks
         let mut house_digits = HouseArray([Set::NONE; N_HOUSES]);
-
         for (cell, &content) in Cell::all().zip(self.0.iter()) {
-            let digit = match Digit::new_checked(content) {
-                None => return false,
-                Some(digit) => digit.as_set(),
-            };
-
-            house_digits[cell.row()] |= digit;
-            house_digits[cell.col()] |= digit;
-            house_digits[cell.block()] |= digit;
+            if let Some(digit) = Digit::new_checked(content) {
+                house_digits[cell.row()] |= digit.as_set();
+                house_digits[cell.col()] |= digit.as_set();
+                house_digits[cell.block()] |= digit.as_set();
+            }
         }
 
-        house_digits == HouseArray([Set::ALL; N_HOUSES])
+        let mut candidates = [Set::NONE; N_CELLS];
+        for (cell, &content) in Cell::all().zip(self.0.iter()) {
+            if content == 0 {
+                candidates[cell.as_index()] = Set::ALL
+                    .without(house_digits[cell.row()])
+                    .without(house_digits[cell.col()])
+                    .without(house_digits[cell.block()]);
+            }
+        }
+        candidates
     }
 
     /// Returns number of filled cells
@@ -645,6 +2645,16 @@ impl Sudoku {
         self.0.iter().filter(|&&num| num != 0).count() as u8
     }
 
+    /// Returns number of empty cells. This is always `81 - self.n_clues()`.
+    pub fn n_empty(&self) -> u8 {
+        N_CELLS as u8 - self.n_clues()
+    }
+
+    /// Returns `true`, if no cell is filled.
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&num| num == 0)
+    }
+
     /// Perform various transformations that create a different but equivalent sudoku.
     /// The transformations preserve the sudoku's validity and the amount of solutions
     /// as well a the applicability of solution strategies.
@@ -676,6 +2686,60 @@ impl Sudoku {
         self
     }
 
+    /// Swaps all occurrences of the digits `a` and `b`. Empty cells are left alone.
+    ///
+    /// This is the simplest relabeling of the digits, see [`Transform::relabel`](crate::board::Transform::relabel)
+    /// for arbitrary ones.
+    ///
+    /// # Panics
+    /// Panics, if `a` or `b` is not in range 1..=9.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// sudoku.swap_digits(1, 2);
+    /// assert_eq!(sudoku.as_bytes()[3], 1);
+    /// assert_eq!(sudoku.as_bytes()[17], 2);
+    /// ```
+    pub fn swap_digits(&mut self, a: u8, b: u8) {
+        assert!(
+            (1..=9).contains(&a) && (1..=9).contains(&b),
+            "digits must be in range 1..=9"
+        );
+        for num in self.0.iter_mut() {
+            if *num == a {
+                *num = b;
+            } else if *num == b {
+                *num = a;
+            }
+        }
+    }
+
+    /// Transforms the sudoku into an equivalent one, see [`Transform`](crate::board::Transform).
+    ///
+    /// ```
+    /// use sudoku::{board::Transform, Sudoku};
+    ///
+    /// let mut sudoku = Sudoku::generate();
+    /// let solution = sudoku.solution().unwrap();
+    /// let transform = Transform::identity().transpose().swap_bands(0, 2).relabel([9, 8, 7, 6, 5, 4, 3, 2, 1]);
+    /// sudoku.apply(&transform);
+    /// assert_eq!(sudoku.solution(), Some(solution.with_transform(&transform)));
+    /// ```
+    pub fn apply(&mut self, transform: &crate::board::Transform) {
+        transform.apply(self);
+    }
+
+    /// Returns a copy of the sudoku [transformed](Sudoku::apply) by `transform`.
+    pub fn with_transform(mut self, transform: &crate::board::Transform) -> Self {
+        self.apply(transform);
+        self
+    }
+
     /// Returns the canonical representation of this sudoku and its automorphism count.
     ///
     /// All sudokus that can be translated into each other via validity preserving transformations belong to the same
@@ -707,17 +2771,301 @@ impl Sudoku {
         Some((sudoku, n_automorphisms))
     }
 
+    /// Returns the line format of the [canonical representation](Sudoku::canonicalized) of this sudoku.
+    ///
+    /// Equivalent sudokus have the same canonical line, so it can be used as a key in
+    /// hash sets and maps for deduplication.
+    ///
+    /// Limited to uniquely solvable sudokus. Returns `None` otherwise.
+    pub fn canonical_line(&self) -> Option<SudokuLine> {
+        self.canonicalized().map(|(canonical, _)| canonical.to_str_line())
+    }
+
+    /// Returns a cheap hash of the sudoku that is the same for all equivalent sudokus.
+    ///
+    /// Sudokus that can be transformed into each other by the transformations listed in [`Sudoku::shuffle`]
+    /// are guaranteed to have the same fingerprint, so there are no false negatives. Different sudokus
+    /// may share a fingerprint, too. Use [`Sudoku::canonicalized`] to decide whether sudokus with equal
+    /// fingerprints are actually equivalent. Unlike canonicalization, this doesn't require solving the sudoku.
+    ///
+    /// The fingerprint is computed from the number of clues per house and digit, and the number of
+    /// candidates per empty cell, all of which are invariant under these transformations.
+    /// The exact values may change between versions.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::generate();
+    /// assert_eq!(sudoku.fingerprint(), sudoku.shuffled().fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        use super::positions::{block, col, row};
+
+        let mut row_clues = [0u8; 9];
+        let mut col_clues = [0u8; 9];
+        let mut block_clues = [0u8; 9];
+        let mut digit_clues = [0u8; 9];
+        for (cell, &num) in (0..).zip(self.0.iter()) {
+            if num != 0 {
+                row_clues[row(cell) as usize] += 1;
+                col_clues[col(cell) as usize] += 1;
+                block_clues[block(cell) as usize] += 1;
+                digit_clues[num as usize - 1] += 1;
+            }
+        }
+        // rows and columns are swapped by transposition, so their order must not matter
+        row_clues.sort_unstable();
+        col_clues.sort_unstable();
+        let (lines_a, lines_b) = match row_clues <= col_clues {
+            true => (row_clues, col_clues),
+            false => (col_clues, row_clues),
+        };
+        block_clues.sort_unstable();
+        digit_clues.sort_unstable();
+
+        let mut candidate_histogram = [0u8; 10];
+        for (&num, candidates) in self.0.iter().zip(self.cell_candidates().iter()) {
+            if num == 0 {
+                candidate_histogram[candidates.len() as usize] += 1;
+            }
+        }
+
+        // FNV-1a
+        let features = [lines_a, lines_b, block_clues, digit_clues];
+        features
+            .iter()
+            .flat_map(|feature| feature.iter())
+            .chain(candidate_histogram.iter())
+            .fold(0xcbf2_9ce4_8422_2325, |hash: u64, &byte| {
+                (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
+    /// Returns the entries of the 20 cells that share a row, column or block with `cell`.
+    /// Empty cells are `None`. The entries are in the order of [`board::positions::neighbors`](crate::board::positions::neighbors).
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let peers = sudoku.peers(0);
+    ///
+    /// assert_eq!(peers.len(), 20);
+    /// assert!(peers.contains(&Some(2)));
+    /// assert!(!peers.contains(&Some(9)));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics, if `cell` is outside of `0..81`.
+    pub fn peers(&self, cell: u8) -> Vec<Option<u8>> {
+        super::positions::neighbors(cell)
+            .iter()
+            .map(|&neighbor| num_to_opt(&self.0[neighbor as usize]))
+            .collect()
+    }
+
     /// Returns an Iterator over sudoku, going from left to right, top to bottom
     pub fn iter(&self) -> Iter {
         self.0.iter().map(num_to_opt)
     }
 
+    /// Calls `f` with the index and content of every cell, going from left to right, top to bottom.
+    /// Changes made by `f` to the content are written back into the sudoku.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut sudoku = Sudoku::generate_solved();
+    /// // clear the main diagonal
+    /// sudoku.for_each_cell_mut(|cell, content| {
+    ///     if cell % 10 == 0 {
+    ///         *content = None;
+    ///     }
+    /// });
+    /// assert_eq!(sudoku.n_clues(), 72);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics, if `f` sets the content of a cell to a digit outside of `1..=9`.
+    pub fn for_each_cell_mut<F: FnMut(u8, &mut Option<u8>)>(&mut self, mut f: F) {
+        for (cell, num) in (0..).zip(self.0.iter_mut()) {
+            let mut content = num_to_opt(num);
+            f(cell, &mut content);
+            *num = match content {
+                Some(digit) => {
+                    assert!(
+                        (1..=9).contains(&digit),
+                        "invalid digit {} in cell {}",
+                        digit,
+                        cell
+                    );
+                    digit
+                }
+                None => 0,
+            };
+        }
+    }
+
+    /// Clears all filled cells for which `pred` returns `true` when called with the cell's index and digit.
+    /// Empty cells are left as they are.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    /// use sudoku::board::positions::block;
+    ///
+    /// let mut sudoku = Sudoku::generate_solved();
+    /// // clear the center block and all even digits
+    /// sudoku.blank_if(|cell, digit| block(cell) == 4 || digit % 2 == 0);
+    ///
+    /// assert!(sudoku.iter().skip(30).take(3).all(|num| num.is_none()));
+    /// assert!(sudoku.iter().flatten().all(|digit| digit % 2 == 1));
+    /// ```
+    pub fn blank_if<F: FnMut(u8, u8) -> bool>(&mut self, mut pred: F) {
+        for (cell, num) in (0..).zip(self.0.iter_mut()) {
+            if *num != 0 && pred(cell, *num) {
+                *num = 0;
+            }
+        }
+    }
+
     /// Returns a byte array for the sudoku.
     /// Empty cells are denoted by 0, clues by the numbers 1-9.
     pub fn to_bytes(self) -> SudokuArray {
         self.0
     }
 
+    /// Returns a byte array for the sudoku like [`Sudoku::to_bytes`], but with empty cells denoted by `marker`.
+    /// Clues are denoted by the numbers 1-9 as usual.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut bytes = [0; 81];
+    /// bytes[0] = 9;
+    /// let sudoku = Sudoku::from_bytes(bytes).unwrap();
+    /// let bytes = sudoku.to_bytes_with_empty(255);
+    ///
+    /// assert_eq!(bytes[0], 9);
+    /// assert!(bytes[1..].iter().all(|&num| num == 255));
+    /// ```
+    pub fn to_bytes_with_empty(&self, marker: u8) -> SudokuArray {
+        let mut bytes = self.0;
+        for num in bytes.iter_mut().filter(|num| **num == 0) {
+            *num = marker;
+        }
+        bytes
+    }
+
+    /// Returns a byte array for the sudoku with the digits numbered from 0-8 instead of 1-9
+    /// and empty cells denoted by `empty_marker`. See [`Sudoku::from_bytes_zero_based`] for the inverse.
+    ///
+    /// # Panics
+    /// Panics, if `empty_marker` is a digit from 0-8.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut bytes = [0; 81];
+    /// bytes[0] = 9;
+    /// let sudoku = Sudoku::from_bytes(bytes).unwrap();
+    /// let bytes = sudoku.to_bytes_zero_based(9);
+    ///
+    /// assert_eq!(bytes[0], 8);
+    /// assert!(bytes[1..].iter().all(|&num| num == 9));
+    /// ```
+    pub fn to_bytes_zero_based(&self, empty_marker: u8) -> SudokuArray {
+        assert!(empty_marker >= 9, "empty marker collides with the digits 0-8");
+        let mut bytes = self.0;
+        for num in bytes.iter_mut() {
+            *num = match *num {
+                0 => empty_marker,
+                digit => digit - 1,
+            };
+        }
+        bytes
+    }
+
+    /// Returns a reference to the byte array of the sudoku.
+    /// Empty cells are denoted by 0, clues by the numbers 1-9.
+    pub fn as_bytes(&self) -> &SudokuArray {
+        &self.0
+    }
+
+    /// Returns the sudoku packed into 4 bits per cell, half the size of [`Sudoku::to_bytes`].
+    /// Empty cells are denoted by 0, clues by the numbers 1-9.
+    ///
+    /// Every byte contains 2 cells, going from left to right, top to bottom, the first one in the
+    /// upper 4 bits. The lower 4 bits of the last byte are padding and always 0.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut bytes = [0; 81];
+    /// bytes[0] = 1;
+    /// bytes[1] = 2;
+    /// bytes[80] = 9;
+    /// let sudoku = Sudoku::from_bytes(bytes).unwrap();
+    /// let packed = sudoku.to_packed();
+    ///
+    /// assert_eq!((packed[0], packed[40]), (0x12, 0x90));
+    /// assert_eq!(Sudoku::from_packed(&packed).unwrap(), sudoku);
+    /// ```
+    pub fn to_packed(&self) -> [u8; 41] {
+        let mut packed = [0; 41];
+        for (byte, pair) in packed.iter_mut().zip(self.0.chunks(2)) {
+            *byte = pair[0] << 4 | pair.get(1).copied().unwrap_or(0);
+        }
+        packed
+    }
+
+    /// Returns a code of 55 characters for sharing the sudoku, e.g. in URLs.
+    ///
+    /// The code is the [packed representation](Sudoku::to_packed) in the URL-safe base64 alphabet
+    /// (`A-Z`, `a-z`, `0-9`, `-` and `_`) without padding. It can be read back with [`Sudoku::from_code`].
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    /// let code = sudoku.to_code();
+    ///
+    /// assert_eq!(code.len(), 55);
+    /// assert_eq!(Sudoku::from_code(&code).unwrap(), sudoku);
+    /// ```
+    pub fn to_code(&self) -> String {
+        super::code::encode(&self.to_packed())
+    }
+
+    /// Returns a versioned binary encoding of 42 bytes for storing the sudoku, e.g. in databases or archive files.
+    ///
+    /// The first byte is the version of the format, currently 1, followed by the
+    /// [packed representation](Sudoku::to_packed). The layout of a version won't change, so the encoding
+    /// can be stored permanently and read back with [`Sudoku::from_binary`].
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::generate();
+    /// let binary = sudoku.to_binary();
+    ///
+    /// assert_eq!(binary.len(), 42);
+    /// assert_eq!(binary[0], 1);
+    /// assert_eq!(&binary[1..], &sudoku.to_packed()[..]);
+    /// assert_eq!(Sudoku::from_binary(&binary).unwrap(), sudoku);
+    /// ```
+    pub fn to_binary(&self) -> Vec<u8> {
+        let mut binary = Vec::with_capacity(42);
+        binary.push(BINARY_FORMAT_VERSION);
+        binary.extend_from_slice(&self.to_packed());
+        binary
+    }
+
     /// Returns a representation of the sudoku in line format that can be printed
     /// and which derefs into a &str
     ///
@@ -747,6 +3095,198 @@ impl Sudoku {
         SudokuLine(chars)
     }
 
+    /// Returns the sudoku in line format as an owned `String`.
+    /// Equivalent to `self.to_str_line().to_string()`.
+    pub fn to_line_string(&self) -> String {
+        self.to_str_line().to_string()
+    }
+
+    /// Returns the sudoku in the block format of [`Sudoku::display_block`] as an owned `String`.
+    pub fn to_block_string(&self) -> String {
+        self.display_block().to_string()
+    }
+
+    /// Returns the sudoku as comma-separated values, one line of 9 fields per row, with empty fields
+    /// for empty cells.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut grid = [0; 81];
+    /// grid[1] = 5;
+    /// let csv = Sudoku::from_bytes(grid).unwrap().to_csv();
+    ///
+    /// assert!(csv.starts_with(",5,,,,,,,\n,,,,,,,,\n"));
+    /// assert_eq!(csv.lines().count(), 9);
+    /// ```
+    pub fn to_csv(&self) -> String {
+        super::csv::to_csv(&self.0)
+    }
+
+    /// Returns the sudoku as nested JSON arrays, one array per row, with 0 for empty cells.
+    /// The output contains no whitespace.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_bytes([0; 81]).unwrap();
+    /// let json = sudoku.to_json_array();
+    ///
+    /// assert!(json.starts_with("[[0,0,0,0,0,0,0,0,0],[0,0,"));
+    /// assert_eq!(Sudoku::from_json_array(&json).unwrap(), sudoku);
+    /// ```
+    pub fn to_json_array(&self) -> String {
+        super::json::to_json_array(&self.0)
+    }
+
+    /// Returns a drawing of the sudoku with borders around the blocks, for printing to terminals.
+    /// Empty cells are printed as `.`.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     sudoku.to_ascii_art(),
+    ///     "\
+    /// +---------+---------+---------+
+    /// | .  .  . | 2  .  . | .  6  3 |
+    /// | 3  .  . | .  .  5 | 4  .  1 |
+    /// | .  .  1 | .  .  3 | 9  8  . |
+    /// +---------+---------+---------+
+    /// | .  .  . | .  .  . | .  9  . |
+    /// | .  .  . | 5  3  8 | .  .  . |
+    /// | .  3  . | .  .  . | .  .  . |
+    /// +---------+---------+---------+
+    /// | .  2  6 | 3  .  . | 5  .  . |
+    /// | 5  .  3 | 7  .  . | .  .  8 |
+    /// | 4  7  . | .  .  1 | .  .  . |
+    /// +---------+---------+---------+
+    /// "
+    /// );
+    /// ```
+    pub fn to_ascii_art(&self) -> String {
+        self.to_ascii_art_with_givens(&[false; N_CELLS])
+    }
+
+    /// Like [`Sudoku::to_ascii_art`], but the cells marked in `givens` are put in brackets, e.g. `[5]`.
+    /// This can be used to distinguish the clues of a puzzle from the entries of its solution
+    /// by passing the puzzle's [`clues_mask`](Sudoku::clues_mask).
+    pub fn to_ascii_art_with_givens(&self, givens: &[bool; N_CELLS]) -> String {
+        const BORDER: &str = "+---------+---------+---------+\n";
+        // 1 border line per band and 1 at the bottom, all 31 chars + newline
+        let mut art = String::with_capacity(13 * 32);
+        for (row, (cells, row_givens)) in self.0.chunks(9).zip(givens.chunks(9)).enumerate() {
+            if row % 3 == 0 {
+                art.push_str(BORDER);
+            }
+            for (col, (&num, &is_given)) in cells.iter().zip(row_givens).enumerate() {
+                if col % 3 == 0 {
+                    art.push('|');
+                }
+                let ch = match num {
+                    0 => '.',
+                    _ => (b'0' + num) as char,
+                };
+                let (left, right) = if is_given { ('[', ']') } else { (' ', ' ') };
+                art.push(left);
+                art.push(ch);
+                art.push(right);
+            }
+            art.push_str("|\n");
+        }
+        art.push_str(BORDER);
+        art
+    }
+
+    /// Returns the sudoku as a grid of pencilmarks as read by Sudoku Explainer and HoDoKu.
+    ///
+    /// Filled cells show their digit, empty cells all their candidates (see [`Sudoku::candidate_counts`]).
+    /// Every column is padded to its widest cell, cells are separated by 2 spaces.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "....19763....65421261473985785126394649538172132947856926384517513792648478651239",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     sudoku.to_explainer_format(),
+    ///     "\
+    /// .----------.----------.---------.
+    /// | 8   5  4 | 28  1  9 | 7  6  3 |
+    /// | 38  9  7 | 8   6  5 | 4  2  1 |
+    /// | 2   6  1 | 4   7  3 | 9  8  5 |
+    /// :----------+----------+---------:
+    /// | 7   8  5 | 1   2  6 | 3  9  4 |
+    /// | 6   4  9 | 5   3  8 | 1  7  2 |
+    /// | 1   3  2 | 9   4  7 | 8  5  6 |
+    /// :----------+----------+---------:
+    /// | 9   2  6 | 3   8  4 | 5  1  7 |
+    /// | 5   1  3 | 7   9  2 | 6  4  8 |
+    /// | 4   7  8 | 6   5  1 | 2  3  9 |
+    /// '----------'----------'---------'
+    /// "
+    /// );
+    /// ```
+    pub fn to_explainer_format(&self) -> String {
+        let candidates = self.cell_candidates();
+        let cells: Vec<String> = self
+            .0
+            .iter()
+            .zip(candidates.iter())
+            .map(|(&num, &candidates)| match num {
+                0 => candidates
+                    .into_iter()
+                    .map(|digit| (b'0' + digit.get()) as char)
+                    .collect(),
+                _ => ((b'0' + num) as char).to_string(),
+            })
+            .collect();
+        let mut widths = [0; 9];
+        for (cell, text) in cells.iter().enumerate() {
+            widths[cell % 9] = std::cmp::max(widths[cell % 9], text.len());
+        }
+
+        // a stack is written as " a  b  c " with each cell padded to the width of its column
+        let stack_width = |stack: usize| widths[stack * 3..stack * 3 + 3].iter().sum::<usize>() + 6;
+        let border = |outer: char, inner: char| {
+            let mut line = String::new();
+            for stack in 0..3 {
+                line.push(if stack == 0 { outer } else { inner });
+                line.push_str(&"-".repeat(stack_width(stack)));
+            }
+            line.push(outer);
+            line.push('\n');
+            line
+        };
+
+        let mut grid = border('.', '.');
+        for (row, row_cells) in cells.chunks(9).enumerate() {
+            if row == 3 || row == 6 {
+                grid.push_str(&border(':', '+'));
+            }
+            for (col, text) in row_cells.iter().enumerate() {
+                grid.push_str(match col {
+                    0 | 3 | 6 => "| ",
+                    _ => "  ",
+                });
+                grid.push_str(&format!("{:width$}", text, width = widths[col]));
+                if col % 3 == 2 {
+                    grid.push(' ');
+                }
+            }
+            grid.push_str("|\n");
+        }
+        grid.push_str(&border('\'', '\''));
+        grid
+    }
+
     /// Returns a value that prints a block representation of the sudoku
     /// when formatted via the `Display` trait.
     ///
@@ -779,6 +3319,45 @@ impl Sudoku {
     pub fn display_block(&self) -> SudokuBlock {
         SudokuBlock(self.0)
     }
+
+    /// Returns `other` in the block format of [`Sudoku::display_block`] with the cells that differ from `self`
+    /// marked by `marker` on both sides. All other cells are padded with spaces, so the columns stay aligned.
+    ///
+    /// This is meant for showing the changes between two grids, e.g. the entries a solver filled in.
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    ///
+    /// let mut grid = [0; 81];
+    /// grid[36..45].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// let sudoku = Sudoku::from_bytes(grid).unwrap();
+    /// grid[3] = 5;
+    /// grid[40] = 0;
+    /// let changed = Sudoku::from_bytes(grid).unwrap();
+    ///
+    /// let diff = sudoku.diff_grid(&changed, '*');
+    /// assert_eq!(diff.lines().next(), Some(" _  _  _  *5* _  _   _  _  _ "));
+    /// assert_eq!(diff.lines().nth(5), Some(" 1  2  3   4 *_* 6   7  8  9 "));
+    /// ```
+    pub fn diff_grid(&self, other: &Sudoku, marker: char) -> String {
+        use std::fmt::Write;
+
+        let mut diff = String::new();
+        write_block(&mut diff, |f, cell| {
+            let padding = match self.0[cell] == other.0[cell] {
+                true => ' ',
+                false => marker,
+            };
+            match other.0[cell] {
+                0 => write!(f, "{}_{}", padding, padding),
+                num => write!(f, "{}{}{}", padding, num, padding),
+            }
+        })
+        .expect("writing to a string can't fail");
+        // the block format starts with a line break
+        diff.remove(0);
+        diff
+    }
 }
 
 #[rustfmt::skip]
@@ -787,6 +3366,17 @@ fn num_to_opt(num: &u8) -> Option<u8> {
     if *num == 0 { None } else { Some(*num) }
 }
 
+// All strategies used for grading except for those that assume a unique solution
+fn strategies_without_uniqueness() -> Vec<crate::strategy::Strategy> {
+    use crate::strategy::Strategy;
+
+    Strategy::ALL
+        .iter()
+        .filter(|strategy| !matches!(strategy, Strategy::UniqueRectangles))
+        .cloned()
+        .collect()
+}
+
 impl fmt::Display for Sudoku {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Display::fmt(&self.to_str_line(), f)
@@ -816,7 +3406,11 @@ impl From<Sudoku> for SudokuArray {
 }
 
 /// Container for the &str representation of a sudoku
-// MUST ALWAYS contain valid utf8
+///
+/// Hashing, equality and ordering are the same as for the `&str` it dereferences to,
+/// so it can be used as a key in sets and maps in place of a `String`.
+// MUST ALWAYS contain only the ASCII characters '1'..='9' and '.',
+// which is ensured by the constructors `Sudoku::to_str_line` and `TryFrom<&str>`
 //
 // the bytes representation uses b'.' for empty cells, which is below `0` and therefore
 // this orders just like the regular sudoku would.
@@ -838,10 +3432,52 @@ impl fmt::Debug for SudokuLine {
     }
 }
 
+impl SudokuLine {
+    /// Returns the sudoku in line format as a `&str`.
+    ///
+    /// It always consists of exactly 81 ASCII characters, the digits 1-9 for filled cells and `'.'` for empty cells.
+    pub fn as_str(&self) -> &str {
+        debug_assert!(self.0.iter().all(|&ch| ch == b'.' || (b'1'..=b'9').contains(&ch)));
+        str::from_utf8(&self.0).unwrap()
+    }
+}
+
 impl ops::Deref for SudokuLine {
     type Target = str;
     fn deref(&self) -> &Self::Target {
-        str::from_utf8(&self.0).unwrap()
+        self.as_str()
+    }
+}
+
+/// Checks that the string is a sudoku in the exact format of [`Sudoku::to_str_line`], i.e. 81 characters,
+/// each of which is either a digit from 1 to 9 or `'.'` for an empty cell.
+/// Unlike [`Sudoku::from_str_line`], no other empty cell markers, whitespace or comments are accepted.
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use sudoku::{board::SudokuLine, Sudoku};
+///
+/// let sudoku = Sudoku::generate();
+/// let line = SudokuLine::try_from(sudoku.to_line_string().as_str()).unwrap();
+/// assert_eq!(line, sudoku.to_str_line());
+/// ```
+impl TryFrom<&str> for SudokuLine {
+    type Error = LineParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut line = [0; N_CELLS];
+        let mut chars = s.chars();
+        for (cell, byte) in (0..).zip(line.iter_mut()) {
+            *byte = match chars.next() {
+                Some(ch @ '1'..='9') | Some(ch @ '.') => ch as u8,
+                Some(ch) => return Err(LineParseError::InvalidEntry(InvalidEntry { cell, ch })),
+                None => return Err(LineParseError::NotEnoughCells(cell)),
+            };
+        }
+        match chars.next() {
+            Some(_) => Err(LineParseError::TooManyCells),
+            None => Ok(SudokuLine(line)),
+        }
     }
 }
 
@@ -858,23 +3494,31 @@ pub struct SudokuBlock(SudokuArray);
 
 impl fmt::Display for SudokuBlock {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use crate::board::{Cell, Digit};
-        for (digit, cell) in self.0.iter().cloned().map(Digit::new_checked).zip(Cell::all()) {
-            #[rustfmt::skip]
-            #[allow(clippy::write_with_newline)]
-            match (cell.row().get(), cell.col().get()) {
-                (_, 3) | (_, 6) => write!(f, " ")?,    // seperate fields in columns
-                (3, 0) | (6, 0) => write!(f, "\n\n")?, // separate fields in rows
-                (_, 0)          => write!(f, "\n")?,   // separate lines not between fields
-                _ => {},
-            };
-            match digit {
-                None => write!(f, "_")?,
-                Some(dig) => write!(f, "{}", dig.get())?,
-            };
-        }
-        Ok(())
+        write_block(f, |f, cell| match self.0[cell] {
+            0 => write!(f, "_"),
+            num => write!(f, "{}", num),
+        })
+    }
+}
+
+// Writes the separators of the block format and lets `write_cell` write the contents of each cell
+fn write_block<W: fmt::Write>(
+    f: &mut W,
+    mut write_cell: impl FnMut(&mut W, usize) -> fmt::Result,
+) -> fmt::Result {
+    use crate::board::Cell;
+    for cell in Cell::all() {
+        #[rustfmt::skip]
+        #[allow(clippy::write_with_newline)]
+        match (cell.row().get(), cell.col().get()) {
+            (_, 3) | (_, 6) => write!(f, " ")?,    // seperate fields in columns
+            (3, 0) | (6, 0) => write!(f, "\n\n")?, // separate fields in rows
+            (_, 0)          => write!(f, "\n")?,   // separate lines not between fields
+            _ => {},
+        };
+        write_cell(f, cell.as_index())?;
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -0,0 +1,192 @@
+use crate::consts::N_CELLS;
+use crate::Sudoku;
+use rand::{seq::SliceRandom, Rng};
+
+/// A validity preserving transformation of a sudoku, composed of a relabeling of the digits
+/// and a rearrangement of the cells.
+///
+/// Transforms are built up from the identity by chaining the elementary operations listed in
+/// [`Sudoku::shuffle`], i.e. [`relabel`](Transform::relabel), [`swap_rows`](Transform::swap_rows),
+/// [`swap_cols`](Transform::swap_cols), [`swap_bands`](Transform::swap_bands),
+/// [`swap_stacks`](Transform::swap_stacks) and [`transpose`](Transform::transpose).
+/// Every operation is performed after the ones before it. Transforms can be combined with
+/// [`then`](Transform::then), undone with [`invert`](Transform::invert) and applied with
+/// [`Sudoku::apply`] or [`Sudoku::with_transform`].
+///
+/// ```
+/// use sudoku::{board::Transform, Sudoku};
+///
+/// let sudoku = Sudoku::generate();
+/// let transform = Transform::random(&mut rand::thread_rng());
+/// let shuffled = sudoku.with_transform(&transform);
+/// assert_eq!(shuffled.with_transform(&transform.invert()), sudoku);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Transform {
+    // cell of the original sudoku that is moved into each cell
+    cells: [u8; N_CELLS],
+    // new digit for each digit, 0 stays 0
+    digits: [u8; 10],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+impl Transform {
+    /// The transform that leaves every sudoku unchanged.
+    pub fn identity() -> Self {
+        let mut cells = [0; N_CELLS];
+        for (cell, source) in cells.iter_mut().enumerate() {
+            *source = cell as u8;
+        }
+        Transform {
+            cells,
+            digits: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+        }
+    }
+
+    /// Returns a uniformly random transform out of all the combinations listed in [`Sudoku::shuffle`].
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut digits = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        digits.shuffle(rng);
+
+        let mut transform = Transform::identity().relabel(digits);
+        if rng.gen() {
+            transform = transform.transpose();
+        }
+        // Fisher-Yates shuffles of 3 elements each
+        let mut shuffle3 = |transform: Transform, swap: &dyn Fn(Transform, u8, u8) -> Transform| {
+            let mut transform = transform;
+            for i in (1..3).rev() {
+                transform = swap(transform, i, rng.gen_range(0, i + 1));
+            }
+            transform
+        };
+        transform = shuffle3(transform, &Transform::swap_bands);
+        transform = shuffle3(transform, &Transform::swap_stacks);
+        for chute in 0..3 {
+            let offset = 3 * chute;
+            transform = shuffle3(transform, &|t, a, b| t.swap_rows(offset + a, offset + b));
+            transform = shuffle3(transform, &|t, a, b| t.swap_cols(offset + a, offset + b));
+        }
+        transform
+    }
+
+    /// Relabels the digits so that every digit `d` is replaced by `mapping[d - 1]`.
+    ///
+    /// # Panics
+    /// Panics, if `mapping` is not a permutation of the digits 1 to 9.
+    pub fn relabel(mut self, mapping: [u8; 9]) -> Self {
+        let mut seen = [false; 9];
+        for &digit in &mapping {
+            assert!(
+                (1..=9).contains(&digit) && !seen[digit as usize - 1],
+                "mapping must be a permutation of the digits 1 to 9"
+            );
+            seen[digit as usize - 1] = true;
+        }
+        for digit in &mut self.digits[1..] {
+            *digit = mapping[*digit as usize - 1];
+        }
+        self
+    }
+
+    /// Mirrors the sudoku along the diagonal from the top left to the bottom right.
+    pub fn transpose(self) -> Self {
+        self.move_cells(|row, col| (col, row))
+    }
+
+    /// Swaps 2 rows of the same band. Rows are numbered from 0 to 8.
+    ///
+    /// # Panics
+    /// Panics, if the rows are out of range or in different bands.
+    pub fn swap_rows(self, row1: u8, row2: u8) -> Self {
+        assert!(row1 < 9 && row2 < 9, "rows must be in range 0..9");
+        assert!(row1 / 3 == row2 / 3, "rows must be in the same band");
+        self.move_cells(|row, col| (swapped(row, row1, row2), col))
+    }
+
+    /// Swaps 2 columns of the same stack. Columns are numbered from 0 to 8.
+    ///
+    /// # Panics
+    /// Panics, if the columns are out of range or in different stacks.
+    pub fn swap_cols(self, col1: u8, col2: u8) -> Self {
+        assert!(col1 < 9 && col2 < 9, "columns must be in range 0..9");
+        assert!(col1 / 3 == col2 / 3, "columns must be in the same stack");
+        self.move_cells(|row, col| (row, swapped(col, col1, col2)))
+    }
+
+    /// Swaps 2 bands. Bands are numbered from 0 to 2.
+    ///
+    /// # Panics
+    /// Panics, if the bands are out of range.
+    pub fn swap_bands(self, band1: u8, band2: u8) -> Self {
+        assert!(band1 < 3 && band2 < 3, "bands must be in range 0..3");
+        self.move_cells(|row, col| (3 * swapped(row / 3, band1, band2) + row % 3, col))
+    }
+
+    /// Swaps 2 stacks. Stacks are numbered from 0 to 2.
+    ///
+    /// # Panics
+    /// Panics, if the stacks are out of range.
+    pub fn swap_stacks(self, stack1: u8, stack2: u8) -> Self {
+        assert!(stack1 < 3 && stack2 < 3, "stacks must be in range 0..3");
+        self.move_cells(|row, col| (row, 3 * swapped(col / 3, stack1, stack2) + col % 3))
+    }
+
+    /// Returns the transform that performs `self` first and `other` afterwards.
+    pub fn then(&self, other: &Transform) -> Self {
+        let mut cells = [0; N_CELLS];
+        for (source, &other_source) in cells.iter_mut().zip(other.cells.iter()) {
+            *source = self.cells[other_source as usize];
+        }
+        let mut digits = [0; 10];
+        for (digit, &own_digit) in digits.iter_mut().zip(self.digits.iter()) {
+            *digit = other.digits[own_digit as usize];
+        }
+        Transform { cells, digits }
+    }
+
+    /// Returns the transform that undoes `self`.
+    pub fn invert(&self) -> Self {
+        let mut cells = [0; N_CELLS];
+        for (cell, &source) in self.cells.iter().enumerate() {
+            cells[source as usize] = cell as u8;
+        }
+        let mut digits = [0; 10];
+        for (digit, &new_digit) in self.digits.iter().enumerate() {
+            digits[new_digit as usize] = digit as u8;
+        }
+        Transform { cells, digits }
+    }
+
+    pub(crate) fn apply(&self, sudoku: &mut Sudoku) {
+        let original = sudoku.0;
+        for (num, &source) in sudoku.0.iter_mut().zip(self.cells.iter()) {
+            *num = self.digits[original[source as usize] as usize];
+        }
+    }
+
+    // Appends a rearrangement of the cells. `new_position` maps the row and column of a cell
+    // to the row and column that it is moved to.
+    fn move_cells(self, new_position: impl Fn(u8, u8) -> (u8, u8)) -> Self {
+        let mut cells = [0; N_CELLS];
+        for (cell, &source) in self.cells.iter().enumerate() {
+            let (row, col) = new_position(cell as u8 / 9, cell as u8 % 9);
+            cells[row as usize * 9 + col as usize] = source;
+        }
+        Transform { cells, ..self }
+    }
+}
+
+// Returns `val` with `a` and `b` exchanged
+fn swapped(val: u8, a: u8, b: u8) -> u8 {
+    match val {
+        _ if val == a => b,
+        _ if val == b => a,
+        _ => val,
+    }
+}
@@ -1,4 +1,4 @@
-//! Errors that may occur when reading sudokus
+//! Errors that may occur when reading, combining or solving sudokus
 #[cfg(doc)]
 use crate::Sudoku;
 
@@ -7,6 +7,61 @@ use crate::Sudoku;
 #[error("byte array contains entries >9")]
 pub struct FromBytesError(pub(crate) ());
 
+/// Error for [`Sudoku::from_packed`]
+#[derive(Debug, thiserror::Error)]
+#[error("packed array contains entries >9 or non-zero padding")]
+pub struct FromPackedError(pub(crate) ());
+
+/// Error for [`Sudoku::from_code`]
+#[derive(Debug, thiserror::Error)]
+pub enum FromCodeError {
+    /// Character outside of the URL-safe base64 alphabet or, for the last character, one with
+    /// non-zero padding bits. Contains the byte position and the character.
+    #[error("invalid character '{ch}' at position {pos}")]
+    InvalidChar {
+        /// Byte position of the character in the code
+        pos: usize,
+        /// The invalid character
+        ch: char,
+    },
+    /// Codes are always 55 characters long. Contains the length found.
+    #[error("code should have 55 characters, found {0}")]
+    WrongLength(usize),
+    /// The decoded sudoku contains invalid entries
+    #[error(transparent)]
+    InvalidEntries(FromPackedError),
+}
+
+/// Error for [`Sudoku::from_binary`]
+#[derive(Debug, thiserror::Error)]
+pub enum FromBinaryError {
+    /// The encoding is always 42 bytes long. Contains the length found.
+    #[error("binary sudoku should have 42 bytes, found {0}")]
+    WrongLength(usize),
+    /// The version in the first byte is not supported. Contains the version found.
+    #[error("unsupported binary format version {0}")]
+    UnsupportedVersion(u8),
+    /// The decoded sudoku contains invalid entries
+    #[error(transparent)]
+    InvalidEntries(FromPackedError),
+}
+
+/// Error for [`Sudoku::from_bytes_zero_based`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum FromZeroBasedError {
+    /// The empty marker is one of the digits 0-8. Contains the marker.
+    #[error("empty marker {0} collides with the digits 0-8")]
+    InvalidEmptyMarker(u8),
+    /// A byte is neither a digit from 0-8 nor the empty marker
+    #[error("cell {cell} contains invalid byte {byte}")]
+    InvalidEntry {
+        /// Cell number goes from 0..=80, 0..=8 for first line, 9..=17 for 2nd and so on
+        cell: u8,
+        /// The invalid byte
+        byte: u8,
+    },
+}
+
 /// Error for [`Sudoku::from_bytes_slice`]
 #[derive(Debug, thiserror::Error)]
 pub enum FromBytesSliceError {
@@ -18,6 +73,33 @@ pub enum FromBytesSliceError {
     FromBytesError(FromBytesError),
 }
 
+/// Error for [`Sudoku::from_entries`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum FromEntriesError {
+    /// The cell index is not in `0..81`. Contains the cell index.
+    #[error("cell index {0} out of range")]
+    InvalidCell(u8),
+    /// The digit is not in `1..=9`
+    #[error("cell {cell} is assigned invalid digit {digit}")]
+    InvalidDigit {
+        /// Cell number goes from 0..=80, 0..=8 for first line, 9..=17 for 2nd and so on
+        cell: u8,
+        /// The invalid digit
+        digit: u8,
+    },
+    /// The cell is assigned two different digits
+    #[error("cell {cell} is assigned both {digit} and {other_digit}")]
+    ConflictingEntries {
+        /// Cell number goes from 0..=80, 0..=8 for first line, 9..=17 for 2nd and so on
+        cell: u8,
+        /// The digit assigned first
+        digit: u8,
+        /// The digit assigned later
+        other_digit: u8,
+    },
+}
+
+use crate::board::positions::HouseType;
 use crate::board::{block, col, row};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
@@ -96,3 +178,228 @@ pub enum LineParseError {
     #[error("missing comment delimiter")]
     MissingCommentDelimiter,
 }
+
+/// Error for [`Sudoku::from_json_array`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum JsonParseError {
+    /// Character that doesn't fit the expected array structure, with its byte position in the input
+    #[error("unexpected character '{ch}' at position {pos}")]
+    UnexpectedChar {
+        /// Byte position of the character in the input
+        pos: usize,
+        /// The unexpected character
+        ch: char,
+    },
+    /// Input ended before the outer array was closed
+    #[error("unexpected end of input")]
+    UnexpectedEnd,
+    /// Number that is not a valid cell entry. Valid entries are 0 for empty cells and 1-9.
+    #[error("invalid entry {value} at position {pos}")]
+    InvalidEntry {
+        /// Byte position of the number in the input
+        pos: usize,
+        /// The parsed number, saturated at `u32::MAX`
+        value: u32,
+    },
+    /// Row with a number of entries other than 9. Longer rows are reported with length 10.
+    #[error("row {row} should have 9 entries, found {len}")]
+    WrongRowLength {
+        /// Row index from 0..=8, topmost row is 0
+        row: u8,
+        /// Number of entries found
+        len: u8,
+    },
+    /// Number of rows other than 9. More rows are reported as 10.
+    #[error("a sudoku should have 9 rows, found {0}")]
+    WrongNumberOfRows(u8),
+}
+
+/// Error for [`Sudoku::from_csv`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum CsvParseError {
+    /// Field that is neither empty nor a number from 0 to 9
+    #[error("cell {cell} contains invalid entry '{field}'")]
+    InvalidEntry {
+        /// Cell number goes from 0..=80, 0..=8 for first line, 9..=17 for 2nd and so on
+        cell: u8,
+        /// The field with surrounding whitespace removed
+        field: String,
+    },
+    /// Row with a number of fields other than 9. Longer rows are reported with length 10.
+    #[error("row {row} should have 9 fields, found {len}")]
+    WrongRowLength {
+        /// Row index from 0..=8, topmost row is 0
+        row: u8,
+        /// Number of fields found
+        len: u8,
+    },
+    /// Number of non-empty lines other than 9. More rows are reported as 10.
+    #[error("a sudoku should have 9 rows, found {0}")]
+    WrongNumberOfRows(u8),
+}
+
+/// Error for [`Sudoku::try_place`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum PlacementError {
+    /// Only the digits 1-9 can be placed
+    #[error("{0} is not a digit from 1 to 9")]
+    InvalidDigit(u8),
+    /// The cell already contains a digit
+    #[error("cell {cell} already contains {digit}")]
+    CellFilled {
+        /// Cell number goes from 0..=80, 0..=8 for first line, 9..=17 for 2nd and so on
+        cell: u8,
+        /// The digit in the cell
+        digit: u8,
+    },
+    /// A cell in the same row, column or block already contains the digit
+    #[error("cell {peer} already contains {digit}")]
+    Conflict {
+        /// The digit that was to be placed
+        digit: u8,
+        /// The neighboring cell containing `digit`
+        peer: u8,
+    },
+}
+
+/// Error for [`Puzzle::set`](crate::board::Puzzle::set) and [`Puzzle::clear`](crate::board::Puzzle::clear)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum EditError {
+    /// Only the digits 1-9 can be entered
+    #[error("{0} is not a digit from 1 to 9")]
+    InvalidDigit(u8),
+    /// The cell contains a given, which can't be changed
+    #[error("cell {cell} contains the given {digit}")]
+    Given {
+        /// Cell number goes from 0..=80, 0..=8 for first line, 9..=17 for 2nd and so on
+        cell: u8,
+        /// The given digit in the cell
+        digit: u8,
+    },
+}
+
+/// Error for [`Puzzle::from_str_line`](crate::board::Puzzle::from_str_line)
+#[derive(Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum PuzzleParseError {
+    /// The givens are not a sudoku in line format
+    #[error("invalid givens: {0}")]
+    Givens(LineParseError),
+    /// The fills are not a sudoku in line format
+    #[error("invalid fills: {0}")]
+    Fills(LineParseError),
+    /// The fills contain an entry in a cell that contains a given
+    #[error("cell {0} is both given and filled")]
+    FillOnGiven(u8),
+}
+
+/// Error for [`Sudoku::try_solve`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum SolveError {
+    /// The sudoku has no solution
+    #[error("sudoku has no solution")]
+    NoSolution,
+    /// The sudoku has more than one solution
+    #[error("sudoku has multiple solutions")]
+    MultipleSolutions,
+}
+
+/// Error for [`Sudoku::solutions_up_to_with_budget`]. The search was aborted, because it exceeded its budget.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+#[error("search aborted after visiting {max_nodes} nodes")]
+pub struct Timeout {
+    /// The maximum number of search nodes that was given
+    pub max_nodes: u64,
+}
+
+/// Error for a single game of [`batch::from_opensudoku`](crate::batch::from_opensudoku)
+#[derive(Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+pub enum OpenSudokuError {
+    /// The `game` element has no `data` attribute
+    #[error("game element without data attribute")]
+    MissingData,
+    /// The `data` attribute is not a sudoku in line format
+    #[error(transparent)]
+    InvalidData(LineParseError),
+}
+
+/// Error for [`batch::from_counted_lines`](crate::batch::from_counted_lines)
+#[derive(Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+pub enum CountedLinesError {
+    /// A line is not a sudoku in line format
+    #[error("line {line}: {error}")]
+    InvalidLine {
+        /// Line number, starting at 1
+        line: usize,
+        /// The parse error of the line
+        error: LineParseError,
+    },
+    /// The count in the first line differs from the number of sudokus
+    #[error("expected {expected} sudokus, found {found}")]
+    CountMismatch {
+        /// The count in the first line
+        expected: usize,
+        /// The number of sudokus read
+        found: usize,
+    },
+}
+
+/// Error for [`Sudoku::overlay`] and [`Sudoku::fill_in`]. The sudokus contain different digits in the same cell.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+#[error("cell {cell} contains {digit} in one sudoku and {other_digit} in the other")]
+pub struct OverlayError {
+    /// Cell number goes from 0..=80, 0..=8 for first line, 9..=17 for 2nd and so on
+    pub cell: u8,
+    /// Digit in the cell of the first sudoku
+    pub digit: u8,
+    /// Digit in the cell of the second sudoku
+    pub other_digit: u8,
+}
+
+/// Error for [`Sudoku::some_solution_or_contradiction`]. Describes why a sudoku has no solution.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum Contradiction {
+    /// The digit must be entered into the cell, but another cell in the same house already contains it.
+    #[error("cell {cell} must contain {digit}, but a neighboring cell already does")]
+    ConflictingEntry {
+        /// Cell number goes from 0..=80, 0..=8 for first line, 9..=17 for 2nd and so on
+        cell: u8,
+        /// The conflicting digit
+        digit: u8,
+    },
+    /// All candidates of the cell have been eliminated.
+    #[error("cell {cell} has no candidates left")]
+    NoCandidates {
+        /// Cell number goes from 0..=80, 0..=8 for first line, 9..=17 for 2nd and so on
+        cell: u8,
+    },
+    /// The digit can't be placed in any cell of the house.
+    #[error("{} has no place left for {digit}", house_name(house))]
+    MissingDigit {
+        /// The row, column or block that is missing the digit
+        house: HouseType,
+        /// The missing digit
+        digit: u8,
+    },
+    /// No contradiction could be found without guessing, but every guess leads to one.
+    #[error("every guess leads to a contradiction")]
+    SearchExhausted,
+}
+
+fn house_name(house: &HouseType) -> String {
+    match house {
+        HouseType::Row(row) => format!("row {}", row.get()),
+        HouseType::Col(col) => format!("column {}", col.get()),
+        HouseType::Block(block) => format!("block {}", block.get()),
+    }
+}
+
+/// Error for [`Sudoku::from_reader_line`] and [`Sudoku::from_reader_block`]
+#[derive(Debug, thiserror::Error)]
+pub enum ReadError<E> {
+    /// Reading from the input failed
+    #[error("failed to read sudoku: {0}")]
+    Io(#[from] std::io::Error),
+    /// The input was read, but doesn't contain a valid sudoku
+    #[error(transparent)]
+    Parse(E),
+}
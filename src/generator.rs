@@ -5,6 +5,7 @@ use crate::bitset::Set;
 use crate::board::Candidate;
 use crate::board::*;
 use crate::consts::*;
+use crate::errors::Contradiction;
 use crate::helper::{CellArray, HouseArray, Unsolvable};
 use crate::Sudoku;
 
@@ -18,6 +19,8 @@ pub(crate) struct SudokuGenerator {
     pub cell_poss_digits: CellArray<Set<Digit>>,
     pub house_solved_digits: HouseArray<Set<Digit>>,
     pub last_cell: u8, // last cell checked in guess routine
+    // where the last Unsolvable was encountered, for diagnostics
+    pub contradiction: Option<Contradiction>,
 }
 
 impl SudokuGenerator {
@@ -29,9 +32,17 @@ impl SudokuGenerator {
             cell_poss_digits: CellArray([Set::ALL; N_CELLS]),
             house_solved_digits: HouseArray([Set::NONE; N_HOUSES]),
             last_cell: 0,
+            contradiction: None,
         }
     }
 
+    // Error path for all contradictions
+    #[cold]
+    fn contradiction(&mut self, contradiction: Contradiction) -> Unsolvable {
+        self.contradiction = Some(contradiction);
+        Unsolvable
+    }
+
     #[inline]
     fn _insert_entry(&mut self, entry: Candidate) {
         self.n_solved_cells += 1;
@@ -67,7 +78,10 @@ impl SudokuGenerator {
 
             // is entry still possible?
             if (self.cell_poss_digits[entry.cell] & entry_mask).is_empty() {
-                return Err(Unsolvable);
+                return Err(self.contradiction(Contradiction::ConflictingEntry {
+                    cell: entry.cell.get(),
+                    digit: entry.digit.get(),
+                }));
             }
 
             self._insert_entry(entry);
@@ -104,7 +118,10 @@ impl SudokuGenerator {
                 || self.house_solved_digits[entry.col()].overlaps(entry_mask)
                 || self.house_solved_digits[entry.block()].overlaps(entry_mask)
                 {
-                    return Err(Unsolvable);
+                    return Err(self.contradiction(Contradiction::ConflictingEntry {
+                        cell: entry.cell.get(),
+                        digit: entry.digit.get(),
+                    }));
                 }
             }
 
@@ -143,7 +160,11 @@ impl SudokuGenerator {
                 unsolved |= poss_digits;
             }
             if unsolved | self.house_solved_digits[house] != Set::ALL {
-                return Err(Unsolvable);
+                let missing_digits = !(unsolved | self.house_solved_digits[house]);
+                return Err(self.contradiction(Contradiction::MissingDigit {
+                    house: house.categorize(),
+                    digit: missing_digits.one_possibility().get(),
+                }));
             }
 
             let mut singles = unsolved.without(multiple_unsolved);
@@ -155,7 +176,16 @@ impl SudokuGenerator {
                 let mask = self.cell_poss_digits[cell];
 
                 if let Ok(maybe_unique) = (mask & singles).unique() {
-                    let digit = maybe_unique.ok_or(Unsolvable)?;
+                    let digit = match maybe_unique {
+                        Some(digit) => digit,
+                        // cell is the only place for multiple digits in this house
+                        None => {
+                            return Err(self.contradiction(Contradiction::MissingDigit {
+                                house: house.categorize(),
+                                digit: (mask & singles).into_iter().nth(1).unwrap().get(),
+                            }))
+                        }
+                    };
                     stack.push(Candidate { cell, digit });
 
                     // mark num as found
@@ -208,10 +238,10 @@ impl SudokuGenerator {
     }
 
     #[inline(always)]
-    fn find_good_random_guess(&mut self) -> Candidate {
+    fn find_good_random_guess<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Candidate {
         let best_cell = self.find_cell_min_poss();
         let poss_digits = self.cell_poss_digits[best_cell];
-        let choice = rand::thread_rng().gen_range(0, poss_digits.len());
+        let choice = rng.gen_range(0, poss_digits.len());
         let digit = poss_digits.into_iter().nth(choice as usize).unwrap();
         Candidate {
             digit,
@@ -229,14 +259,20 @@ impl SudokuGenerator {
     ) -> Result<(), Unsolvable> {
         let cell_mask = &mut self.cell_poss_digits[cell];
         cell_mask.remove(impossible);
-        if let Some(digit) = cell_mask.unique()? {
-            stack.push(Candidate { cell, digit });
+        match cell_mask.unique() {
+            Ok(Some(digit)) => stack.push(Candidate { cell, digit }),
+            Ok(None) => {}
+            Err(_) => return Err(self.contradiction(Contradiction::NoCandidates { cell: cell.get() })),
         }
         Ok(())
     }
 
     // for generation of random, filled sudokus
-    fn randomized_solve_one(mut self, stack: &mut Vec<Candidate>) -> Result<Sudoku, Unsolvable> {
+    fn randomized_solve_one<R: Rng + ?Sized>(
+        mut self,
+        stack: &mut Vec<Candidate>,
+        rng: &mut R,
+    ) -> Result<Sudoku, Unsolvable> {
         // insert and deduce in a loop
         // do a random guess when no more deductions are found
         // backtrack on error (via recursion)
@@ -251,9 +287,9 @@ impl SudokuGenerator {
                 continue;
             }
 
-            let entry = self.find_good_random_guess();
+            let entry = self.find_good_random_guess(rng);
             stack.push(entry);
-            if let filled_sudoku @ Ok(_) = self.clone().randomized_solve_one(stack) {
+            if let filled_sudoku @ Ok(_) = self.clone().randomized_solve_one(stack, rng) {
                 return filled_sudoku;
             }
             stack.clear();
@@ -262,12 +298,54 @@ impl SudokuGenerator {
         }
     }
 
+    /// Enter the clues of `sudoku` and deduce naked and hidden singles until nothing more can be found.
+    /// Returns the first contradiction encountered, if any.
+    pub fn find_contradiction(sudoku: Sudoku) -> Option<Contradiction> {
+        let mut generator = Self::new();
+        let mut stack = sudoku
+            .iter()
+            .enumerate()
+            .filter_map(|(cell, digit)| Some(Candidate::new(cell as u8, digit?)))
+            .collect::<Vec<_>>();
+
+        loop {
+            if generator.insert_entries(&mut stack).is_err() {
+                return generator.contradiction;
+            }
+            if generator.is_solved() {
+                return None;
+            }
+            if generator.find_hidden_singles(&mut stack).is_err() {
+                return generator.contradiction;
+            }
+            if stack.is_empty() {
+                return None;
+            }
+        }
+    }
+
     pub fn generate_solved() -> Sudoku {
+        Self::generate_solved_with_rng(&mut rand::thread_rng())
+    }
+
+    pub fn generate_solved_with_rng<R: Rng + ?Sized>(rng: &mut R) -> Sudoku {
+        Self::generate_solved_with_stack(rng, &mut Vec::with_capacity(N_CELLS))
+    }
+
+    // generates `n` solved sudokus, reusing the stack allocation between them
+    pub fn generate_solved_batch<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Vec<Sudoku> {
+        let mut stack = Vec::with_capacity(N_CELLS);
+        (0..n)
+            .map(|_| Self::generate_solved_with_stack(rng, &mut stack))
+            .collect()
+    }
+
+    fn generate_solved_with_stack<R: Rng + ?Sized>(rng: &mut R, stack: &mut Vec<Candidate>) -> Sudoku {
         // fill first row with a permutation of 1...9
         // not necessary, but ~15% faster
-        let mut stack = Vec::with_capacity(N_CELLS);
+        stack.clear();
         let mut perm = [1, 2, 3, 4, 5, 6, 7, 8, 9];
-        perm.shuffle(&mut rand::thread_rng());
+        perm.shuffle(rng);
 
         stack.extend(
             (0..9)
@@ -275,6 +353,6 @@ impl SudokuGenerator {
                 .map(|(cell, &digit)| Candidate::new(cell, digit)),
         );
 
-        Self::new().randomized_solve_one(&mut stack).unwrap()
+        Self::new().randomized_solve_one(stack, rng).unwrap()
     }
 }
@@ -40,13 +40,14 @@
 //! }
 //! ```
 
+pub mod batch;
 pub mod bitset;
 pub mod board;
 mod consts;
 pub mod errors;
 mod generator;
 mod helper;
-mod solver;
+pub mod solver;
 pub mod strategy;
 
 pub use crate::board::Sudoku;
@@ -38,6 +38,19 @@
 //  for a port under the AGPLv3 license in the forum thread
 //      http://forum.enjoysudoku.com/3-77us-solver-2-8g-cpu-testcase-17sodoku-t30470-270.html#p262718
 
+//! Extension points for the fast backtracking solver
+//!
+//! The solver behind [`Sudoku::solutions_up_to`] and related methods has to guess whenever it can't
+//! deduce any more entries. By default, it picks cells with only 2 candidates.
+//! A different heuristic can be supplied by implementing [`GuessStrategy`] and passing it to
+//! [`Sudoku::solutions_up_to_with_guess_strategy`].
+//!
+//! The search tree explored by the solver can be recorded as a [`UniquenessProof`]
+//! and its shape measured with [`TreeStats`].
+//! A [`SolveSession`] solves a sudoku one step at a time, so the process can be watched.
+
+use crate::bitset::Set;
+use crate::board::{Candidate, Cell, Digit, House};
 use crate::helper::Unsolvable;
 use crate::Sudoku;
 use crunchy::unroll;
@@ -49,21 +62,468 @@ const LOW9: u32 = 0o000_000_777;
 
 // When the solver finds a solution it can save it or just count.
 // The latter is marginally faster.
-enum Solutions<'a> {
+enum Found<'a> {
     Count(usize),
     Vector(&'a mut Vec<Sudoku>),
     Buffer(&'a mut [[u8; 81]], usize),
+    // count and keep only the solution with the given index
+    Nth(usize, usize, Option<Sudoku>),
+    // count and pass each solution to a callback, which returns whether to continue
+    Callback(&'a mut dyn FnMut(&Sudoku) -> bool, usize),
 }
 
-impl Solutions<'_> {
+// The solutions found so far and the budget for the rest of the search
+struct Solutions<'a> {
+    found: Found<'a>,
+    // number of search nodes that may still be visited
+    nodes_left: u64,
+    // set when the search was stopped because `nodes_left` reached 0
+    out_of_budget: bool,
+}
+
+impl<'a> Solutions<'a> {
+    fn new(found: Found<'a>) -> Self {
+        Solutions {
+            found,
+            nodes_left: u64::MAX,
+            out_of_budget: false,
+        }
+    }
+
     fn len(&self) -> usize {
-        match self {
-            Solutions::Vector(v) => v.len(),
-            Solutions::Count(len) => *len,
-            Solutions::Buffer(_, len) => *len,
+        match &self.found {
+            Found::Vector(v) => v.len(),
+            Found::Count(len) => *len,
+            Found::Buffer(_, len) => *len,
+            Found::Nth(_, len, _) => *len,
+            Found::Callback(_, len) => *len,
+        }
+    }
+}
+
+/// A heuristic for choosing the cell that the solver guesses on, when no more entries can be deduced.
+///
+/// All candidates of the chosen cell are tried in ascending order.
+/// Cells with few candidates keep the search tree small.
+pub trait GuessStrategy {
+    /// Returns the cell to guess on. It must be one of the unsolved cells of `state`.
+    fn select_cell(&mut self, state: &SolverState<'_>) -> Cell;
+}
+
+/// The guess heuristic used by [`Sudoku::solutions_up_to`] and related methods.
+///
+/// Picks the first cell with only 2 candidates. If none exists, picks the cell with the fewest candidates
+/// among up to 3 unsolved cells.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DefaultGuessStrategy;
+
+impl GuessStrategy for DefaultGuessStrategy {
+    fn select_cell(&mut self, state: &SolverState<'_>) -> Cell {
+        let solver = state.solver;
+        let (band, cell_mask) = match (0..3)
+            .find_map(|band| Some((band, mask_iter(solver.pairs[band]).next()?)))
+        {
+            Some(bivalue_cell) => bivalue_cell,
+            None => {
+                let (_, band, cell_mask) = solver.min_candidates_cell_of_bands().expect("sudoku is solved");
+                (band, cell_mask)
+            }
+        };
+        Cell::new((band * 27 + bit_pos(cell_mask)) as u8)
+    }
+}
+
+/// The deduction passes of the solver that can be turned off, for measuring how much they contribute
+/// to the solving speed. Passes are combined with `|`. The default enables all of them.
+///
+/// The solver alternates between an update of the candidates and a search for naked singles,
+/// before it guesses. The update eliminates candidates by locked candidates and enters hidden singles
+/// in rows. Locked candidates can't be turned off, because they also enforce the rules.
+/// The same solutions are found regardless of the passes, but possibly in a different order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Passes(u8);
+
+impl Passes {
+    /// Only locked candidates are used before guessing.
+    pub const NONE: Passes = Passes(0);
+    /// Entering the digit of cells with only a single candidate
+    pub const NAKED_SINGLES: Passes = Passes(1);
+    /// Entering the digit that has only a single possible cell in a row
+    pub const HIDDEN_SINGLES: Passes = Passes(1 << 1);
+    /// All passes, which is the behavior of [`Sudoku::solutions_up_to`]
+    pub const ALL: Passes = Passes(Passes::NAKED_SINGLES.0 | Passes::HIDDEN_SINGLES.0);
+
+    /// Returns whether all passes in `other` are enabled in `self`.
+    pub fn contains(self, other: Passes) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Default for Passes {
+    fn default() -> Self {
+        Passes::ALL
+    }
+}
+
+impl std::ops::BitOr for Passes {
+    type Output = Passes;
+
+    fn bitor(self, other: Passes) -> Passes {
+        Passes(self.0 | other.0)
+    }
+}
+
+/// The state of the solver at the time of a guess.
+pub struct SolverState<'a> {
+    solver: &'a SudokuSolver,
+}
+
+impl SolverState<'_> {
+    /// Returns the remaining candidates of `cell`. Solved cells have no candidates.
+    pub fn candidates(&self, cell: Cell) -> Set<Digit> {
+        let band = cell.as_index() / 27;
+        let cell_mask = 1 << (cell.as_index() % 27);
+        if self.solver.unsolved_cells[band] & cell_mask == NONE {
+            return Set::NONE;
+        }
+        Digit::all()
+            .filter(|digit| self.solver.poss_cells[digit.as_index() * 3 + band] & cell_mask != NONE)
+            .fold(Set::NONE, |set, digit| set | digit)
+    }
+
+    /// Returns an iterator over all cells that are not yet solved.
+    pub fn unsolved_cells(&self) -> impl Iterator<Item = Cell> + '_ {
+        Cell::all().filter(move |cell| {
+            let band = cell.as_index() / 27;
+            self.solver.unsolved_cells[band] & 1 << (cell.as_index() % 27) != NONE
+        })
+    }
+}
+/// Evidence that a sudoku has exactly one solution, returned by [`Sudoku::uniqueness_proof`].
+///
+/// The proof consists of the solution and the failing leaves of the solver's search tree.
+/// Every leaf is a set of guesses which, together with the clues, leads to a contradiction
+/// by deducing naked singles, hidden singles and locked candidates alone.
+/// Each guess in the tree is made on a single cell and all of its candidates are tried,
+/// so apart from the solution, every branch of the tree ends in one of the refutations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UniquenessProof {
+    solution: Sudoku,
+    refutations: Vec<Refutation>,
+}
+
+impl UniquenessProof {
+    /// Returns the unique solution.
+    pub fn solution(&self) -> Sudoku {
+        self.solution
+    }
+
+    /// Returns the refuted branches of the search tree in the order they were searched.
+    /// Empty, if the sudoku can be solved without guessing.
+    pub fn refutations(&self) -> &[Refutation] {
+        &self.refutations
+    }
+}
+
+/// A branch of the search tree that leads to a contradiction. See [`UniquenessProof`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Refutation {
+    guesses: Vec<Candidate>,
+}
+
+impl Refutation {
+    /// Returns the guesses leading to the contradiction, ordered from the root of the search tree
+    /// to the refuted guess, which is the last one.
+    pub fn guesses(&self) -> &[Candidate] {
+        &self.guesses
+    }
+}
+
+/// The shape of the search tree explored by the solver, returned by [`Sudoku::solve_with_tree_stats`].
+///
+/// The root of the tree is the sudoku before any guess and has depth 0. Each guess on a cell
+/// creates one child node one level deeper for every candidate tried.
+/// Nodes that lead to a contradiction are leaves. The search stops at the first solution.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TreeStats {
+    nodes_per_depth: Vec<u64>,
+}
+
+impl TreeStats {
+    /// Returns the number of nodes visited at each depth, starting with the root.
+    pub fn nodes_per_depth(&self) -> &[u64] {
+        &self.nodes_per_depth
+    }
+
+    /// Returns the total number of nodes visited.
+    pub fn total_nodes(&self) -> u64 {
+        self.nodes_per_depth.iter().sum()
+    }
+
+    /// Returns the depth of the deepest node visited, i.e. the largest number of nested guesses.
+    pub fn max_depth(&self) -> usize {
+        self.nodes_per_depth.len() - 1
+    }
+
+    /// Returns the average number of children of the nodes at each depth except the deepest one.
+    pub fn branching_factors(&self) -> Vec<f64> {
+        self.nodes_per_depth
+            .windows(2)
+            .map(|nodes| nodes[1] as f64 / nodes[0] as f64)
+            .collect()
+    }
+}
+
+/// A single step of a [`SolveSession`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Step {
+    /// The digit is the only candidate of the cell and was entered.
+    NakedSingle(Candidate),
+    /// The cell is the only place for the digit in one of its houses and the digit was entered.
+    HiddenSingle(Candidate),
+    /// No entry could be deduced, so the digit was guessed for the cell.
+    /// The other candidates of the cell are tried after backtracking.
+    Guess(Candidate),
+    /// The grid contained a contradiction and was reset to the state before the last guess
+    /// that still has untried candidates. The next step guesses one of them.
+    Backtrack,
+}
+
+/// A solving process that is advanced one step at a time, returned by [`Sudoku::solve_session`].
+///
+/// Each call to [`step`](SolveSession::step) enters a naked or hidden single, guesses when no single is left,
+/// or backtracks after a contradiction. The grid can be inspected between steps, which allows
+/// animating the solving process. Guesses are made on the first cell with the fewest candidates
+/// and tried in ascending order. The session ends at the first solution or when all guesses are exhausted.
+///
+/// This is much slower than [`Sudoku::solution`] and meant for visualization, not for solving many sudokus.
+/// The session is also an iterator over its steps.
+///
+/// ```
+/// use sudoku::solver::Step;
+/// use sudoku::Sudoku;
+///
+/// let sudoku = Sudoku::from_str_line(
+///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+/// )
+/// .unwrap();
+/// let mut session = sudoku.solve_session();
+/// while let Some(step) = session.step() {
+///     if let Step::NakedSingle(candidate) | Step::HiddenSingle(candidate) | Step::Guess(candidate) = step {
+///         assert_eq!(session.sudoku().to_bytes()[candidate.cell.as_index()], candidate.digit.get());
+///     }
+/// }
+/// assert_eq!(session.solution(), sudoku.solution());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SolveSession {
+    grid: Sudoku,
+    // the grid before each guess that has been made and the candidates not yet tried
+    guesses: Vec<(Sudoku, Cell, Set<Digit>)>,
+    // the last step was a backtrack, the next one retries the latest guess
+    retry_guess: bool,
+    is_finished: bool,
+}
+
+impl SolveSession {
+    pub(crate) fn new(sudoku: Sudoku) -> Self {
+        SolveSession {
+            grid: sudoku,
+            guesses: vec![],
+            retry_guess: false,
+            // conflicts can't be detected by the candidates
+            is_finished: sudoku.is_solved() || !sudoku.conflicts().is_empty(),
+        }
+    }
+
+    /// Advances the solving process by one step. Returns `None`, if the session is finished.
+    pub fn step(&mut self) -> Option<Step> {
+        if self.is_finished {
+            return None;
+        }
+        if self.retry_guess {
+            self.retry_guess = false;
+            let (_, cell, untried) = self.guesses.last_mut().expect("backtracked to a guess");
+            let digit = untried.into_iter().next().expect("guess has untried candidates");
+            untried.remove(digit.as_set());
+            let candidate = Candidate { cell: *cell, digit };
+            return Some(Step::Guess(self.enter(candidate)));
+        }
+
+        let candidates = self.grid.cell_candidates();
+        let step = match self.find_single(&candidates) {
+            Ok(Some(step)) => step,
+            Ok(None) => {
+                let (cell, cell_candidates) = Cell::all()
+                    .filter(|cell| self.grid.0[cell.as_index()] == 0)
+                    .map(|cell| (cell, candidates[cell.as_index()]))
+                    .min_by_key(|&(_, cell_candidates)| cell_candidates.len())
+                    .expect("grid is not yet solved");
+                let digit = cell_candidates.into_iter().next().expect("cell has candidates");
+                self.guesses
+                    .push((self.grid, cell, cell_candidates.without(digit.as_set())));
+                Step::Guess(Candidate { cell, digit })
+            }
+            Err(Unsolvable) => return self.backtrack(),
+        };
+        match step {
+            Step::NakedSingle(candidate) | Step::HiddenSingle(candidate) | Step::Guess(candidate) => {
+                self.enter(candidate);
+            }
+            Step::Backtrack => unreachable!(),
+        }
+        Some(step)
+    }
+
+    // Finds the first naked single or, if there is none, the first hidden single.
+    // Returns an error, if an empty cell has no candidates or a digit has no place left in a house.
+    fn find_single(&self, candidates: &[Set<Digit>; 81]) -> Result<Option<Step>, Unsolvable> {
+        let empty_cells = || Cell::all().filter(|cell| self.grid.0[cell.as_index()] == 0);
+        let mut naked_single = None;
+        for cell in empty_cells() {
+            match candidates[cell.as_index()].unique() {
+                Ok(Some(digit)) if naked_single.is_none() => naked_single = Some(Candidate { cell, digit }),
+                Ok(_) => {}
+                Err(_) => return Err(Unsolvable),
+            }
+        }
+
+        let mut hidden_single = None;
+        for house in House::all() {
+            let mut missing_digits = Set::ALL;
+            for cell in house.cells() {
+                if let Some(digit) = Digit::new_checked(self.grid.0[cell.as_index()]) {
+                    missing_digits.remove(digit.as_set());
+                }
+            }
+            for digit in missing_digits {
+                let mut places = house
+                    .cells()
+                    .into_iter()
+                    .filter(|cell| candidates[cell.as_index()].contains(digit));
+                match (places.next(), places.next()) {
+                    (None, _) => return Err(Unsolvable),
+                    (Some(cell), None) if hidden_single.is_none() => {
+                        hidden_single = Some(Candidate { cell, digit })
+                    }
+                    _ => {}
+                }
+            }
         }
+        Ok(naked_single
+            .map(Step::NakedSingle)
+            .or_else(|| hidden_single.map(Step::HiddenSingle)))
+    }
+
+    fn enter(&mut self, candidate: Candidate) -> Candidate {
+        self.grid.0[candidate.cell.as_index()] = candidate.digit.get();
+        if self.grid.0.iter().all(|&num| num != 0) {
+            self.is_finished = true;
+        }
+        candidate
+    }
+
+    fn backtrack(&mut self) -> Option<Step> {
+        while let Some(&(grid, _, untried)) = self.guesses.last() {
+            if untried.is_empty() {
+                self.guesses.pop();
+                continue;
+            }
+            self.grid = grid;
+            self.retry_guess = true;
+            return Some(Step::Backtrack);
+        }
+        self.is_finished = true;
+        None
+    }
+
+    /// Returns the current grid.
+    pub fn sudoku(&self) -> Sudoku {
+        self.grid
+    }
+
+    /// Returns the number of guesses the current grid is based on.
+    pub fn depth(&self) -> usize {
+        self.guesses.len()
+    }
+
+    /// Returns whether the session is finished, i.e. whether [`step`](SolveSession::step) returns `None`.
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    /// Returns the solution, if the session has found one. It may not be the only one.
+    pub fn solution(&self) -> Option<Sudoku> {
+        match self.grid.is_solved() {
+            true => Some(self.grid),
+            false => None,
+        }
+    }
+}
+
+impl Iterator for SolveSession {
+    type Item = Step;
+
+    fn next(&mut self) -> Option<Step> {
+        self.step()
     }
 }
+
+// Callbacks for the nodes of the search tree visited by `SudokuSolver::visit_tree`.
+// `contradiction` and `solution` return whether the search should continue.
+trait TreeVisitor {
+    // Called for every node before any deductions are made
+    fn node(&mut self, _guesses: &[Candidate]) {}
+    fn contradiction(&mut self, guesses: &[Candidate]) -> bool;
+    fn solution(&mut self, solution: Sudoku) -> bool;
+}
+
+// Collects the refuted branches for a `UniquenessProof` and stops at a second solution
+struct RefutationRecorder {
+    solution: Option<Sudoku>,
+    refutations: Vec<Refutation>,
+    has_multiple_solutions: bool,
+}
+
+impl TreeVisitor for RefutationRecorder {
+    fn contradiction(&mut self, guesses: &[Candidate]) -> bool {
+        self.refutations.push(Refutation {
+            guesses: guesses.to_vec(),
+        });
+        true
+    }
+
+    fn solution(&mut self, solution: Sudoku) -> bool {
+        self.has_multiple_solutions = self.solution.replace(solution).is_some();
+        !self.has_multiple_solutions
+    }
+}
+
+// Counts the nodes per depth for `TreeStats` and stops at the first solution
+struct NodeCounter {
+    stats: TreeStats,
+    solution: Option<Sudoku>,
+}
+
+impl TreeVisitor for NodeCounter {
+    fn node(&mut self, guesses: &[Candidate]) {
+        let nodes_per_depth = &mut self.stats.nodes_per_depth;
+        if nodes_per_depth.len() == guesses.len() {
+            nodes_per_depth.push(0);
+        }
+        nodes_per_depth[guesses.len()] += 1;
+    }
+
+    fn contradiction(&mut self, _: &[Candidate]) -> bool {
+        true
+    }
+
+    fn solution(&mut self, solution: Sudoku) -> bool {
+        self.solution = Some(solution);
+        false
+    }
+}
+
 // Bands  Rows                   Columns
 //
 //               0    1    2    3    4    5    6    7    8
@@ -100,8 +560,9 @@ impl Solutions<'_> {
 // represented by one u32 with up to 27 bits set.
 // They are enumerated as
 // subband = digit * 3 + band
+// The deduction passes are chosen at compile time, so that the default solver doesn't check them
 #[derive(Clone, Copy)]
-pub(crate) struct SudokuSolver {
+pub(crate) struct PassesSolver<const NAKED_SINGLES: bool, const HIDDEN_SINGLES: bool> {
     // possible_cells_in_subband = subbands[digit*3 + band]
     poss_cells: UncheckedIndexArray<u32, 27>,
     prev_poss_cells: UncheckedIndexArray<u32, 27>,
@@ -112,6 +573,8 @@ pub(crate) struct SudokuSolver {
     pairs: UncheckedIndexArray<u32, 3>,
 }
 
+pub(crate) type SudokuSolver = PassesSolver<true, true>;
+
 impl SudokuSolver {
     // jczsolve equivalent: InitSudoku
     pub fn from_sudoku(sudoku: Sudoku) -> Result<Self, Unsolvable> {
@@ -130,28 +593,297 @@ impl SudokuSolver {
         Ok(solver)
     }
 
+    /// Remove the candidates of all digits that are not contained in the `allowed` bitmask of each cell.
+    /// Bit `n` stands for digit `n + 1`. Errors, if a filled cell contains a digit that isn't allowed.
+    pub fn restrict_candidates(&mut self, allowed: &[u16; 81]) -> Result<(), Unsolvable> {
+        for (cell, &allowed) in (0..81).zip(allowed.iter()) {
+            let band = cell / 27;
+            let cell_mask = 1 << (cell % 27);
+            let is_solved = self.unsolved_cells[band] & cell_mask == NONE;
+            for digit in (0..9).filter(|digit| allowed & 1 << digit == 0) {
+                let subband = digit * 3 + band;
+                if is_solved && self.poss_cells[subband] & cell_mask != NONE {
+                    return Err(Unsolvable);
+                }
+                self.poss_cells[subband] &= !cell_mask;
+            }
+        }
+        Ok(())
+    }
+
     /// Find and return up to `limit` solutions
     pub fn solutions_up_to(self, limit: usize) -> Vec<Sudoku> {
         let mut solutions = vec![];
-        self._solutions_up_to(limit, &mut Solutions::Vector(&mut solutions));
+        self._solutions_up_to(limit, &mut Solutions::new(Found::Vector(&mut solutions)));
         solutions
     }
 
     /// Count up to `limit` solutions and save up to buffer.len() of them
     /// in `buffer`. Returns number of solutions.
     pub fn solutions_up_to_buffer(self, buffer: &mut [[u8; 81]], limit: usize) -> usize {
-        let mut solutions = Solutions::Buffer(buffer, 0);
+        let mut solutions = Solutions::new(Found::Buffer(buffer, 0));
         self._solutions_up_to(limit, &mut solutions);
         solutions.len()
     }
 
+    /// Find and return up to `limit` solutions, visiting at most `max_nodes` nodes of the search tree.
+    /// Returns `None`, if the search had to be aborted.
+    pub fn solutions_up_to_with_budget(self, limit: usize, max_nodes: u64) -> Option<Vec<Sudoku>> {
+        let mut vec = vec![];
+        let mut solutions = Solutions::new(Found::Vector(&mut vec));
+        solutions.nodes_left = max_nodes;
+        self._solutions_up_to(limit, &mut solutions);
+        match solutions.out_of_budget {
+            true => None,
+            false => Some(vec),
+        }
+    }
+
     /// Find up to `limit` solutions and return count
     pub fn solutions_count_up_to(self, limit: usize) -> usize {
-        let mut solutions = Solutions::Count(0);
+        let mut solutions = Solutions::new(Found::Count(0));
+        self._solutions_up_to(limit, &mut solutions);
+        solutions.len()
+    }
+
+    /// Find up to `limit` solutions and pass each one to `f` until it returns `false`.
+    /// Returns the number of solutions passed to `f`.
+    pub fn for_each_solution(self, limit: usize, f: &mut dyn FnMut(&Sudoku) -> bool) -> usize {
+        let mut solutions = Solutions::new(Found::Callback(f, 0));
         self._solutions_up_to(limit, &mut solutions);
         solutions.len()
     }
 
+    /// Find the first `n + 1` solutions and return the last one, if that many exist
+    pub fn nth_solution(self, n: usize) -> Option<Sudoku> {
+        let mut solutions = Solutions::new(Found::Nth(n, 0, None));
+        self._solutions_up_to(n.saturating_add(1), &mut solutions);
+        match solutions.found {
+            Found::Nth(_, _, solution) => solution,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Find and return up to `limit` solutions, using only the enabled deduction `passes`
+    pub fn solutions_up_to_with_passes(self, limit: usize, passes: Passes) -> Vec<Sudoku> {
+        let mut vec = vec![];
+        let solutions = &mut Solutions::new(Found::Vector(&mut vec));
+        match (
+            passes.contains(Passes::NAKED_SINGLES),
+            passes.contains(Passes::HIDDEN_SINGLES),
+        ) {
+            (true, true) => self._solutions_up_to(limit, solutions),
+            (true, false) => self
+                .with_passes::<true, false>()
+                ._solutions_up_to(limit, solutions),
+            (false, true) => self
+                .with_passes::<false, true>()
+                ._solutions_up_to(limit, solutions),
+            (false, false) => self
+                .with_passes::<false, false>()
+                ._solutions_up_to(limit, solutions),
+        }
+        vec
+    }
+
+    fn with_passes<const NAKED_SINGLES: bool, const HIDDEN_SINGLES: bool>(
+        self,
+    ) -> PassesSolver<NAKED_SINGLES, HIDDEN_SINGLES> {
+        PassesSolver {
+            poss_cells: self.poss_cells,
+            prev_poss_cells: self.prev_poss_cells,
+            unsolved_cells: self.unsolved_cells,
+            requirement_for_weird_optimization: self.requirement_for_weird_optimization,
+            pairs: self.pairs,
+        }
+    }
+
+    /// Find and return up to `limit` solutions, guessing on the cells chosen by `guess_strategy`
+    pub fn solutions_up_to_with_guess_strategy(
+        mut self,
+        limit: usize,
+        guess_strategy: &mut impl GuessStrategy,
+    ) -> Vec<Sudoku> {
+        let mut vec = vec![];
+        let mut solutions = Solutions::new(Found::Vector(&mut vec));
+        if self.find_naked_singles().is_ok() && self._solve(limit, &mut solutions).is_ok() {
+            self.guess_with_strategy(limit, &mut solutions, guess_strategy);
+        }
+        vec
+    }
+
+    /// Find the lexicographically smallest solution, if any
+    pub fn smallest_solution(self) -> Option<Sudoku> {
+        self.smallest_solutions(1).pop()
+    }
+
+    /// Find the `limit` lexicographically smallest solutions in ascending order
+    pub fn smallest_solutions(mut self, limit: usize) -> Vec<Sudoku> {
+        let mut solutions = vec![];
+        if limit != 0 && self.find_naked_singles().is_ok() {
+            self._smallest_solutions(limit, &mut solutions);
+        }
+        solutions
+    }
+
+    /// Deduce entries until a guess would be necessary and return the grid together
+    /// with the candidates of all unsolved cells. Returns `None` on contradiction.
+    pub fn solve_until_guess(mut self) -> Option<(Sudoku, [Set<Digit>; 81])> {
+        if self.find_naked_singles().is_err() || self.deduce().is_err() {
+            return None;
+        }
+        Some(self.extract_grid_and_candidates())
+    }
+
+    /// Search the complete search tree and record all branches that lead to a contradiction.
+    /// Returns `None`, if the sudoku doesn't have exactly one solution.
+    pub fn uniqueness_proof(self) -> Option<UniquenessProof> {
+        let mut recorder = RefutationRecorder {
+            solution: None,
+            refutations: vec![],
+            has_multiple_solutions: false,
+        };
+        self.visit_tree(&mut vec![], &mut recorder);
+        if recorder.has_multiple_solutions {
+            return None;
+        }
+        Some(UniquenessProof {
+            solution: recorder.solution?,
+            refutations: recorder.refutations,
+        })
+    }
+
+    /// Search for the first solution like `solutions_up_to(1)` and count the nodes of the search tree by depth.
+    /// Takes the result of `from_sudoku`, so that conflicting clues are counted as a failing root.
+    pub fn solve_with_tree_stats(solver: Result<Self, Unsolvable>) -> (Option<Sudoku>, TreeStats) {
+        let mut counter = NodeCounter {
+            stats: TreeStats {
+                nodes_per_depth: vec![],
+            },
+            solution: None,
+        };
+        match solver {
+            Ok(solver) => {
+                solver.visit_tree(&mut vec![], &mut counter);
+            }
+            // clues conflict, the root is the only node
+            Err(Unsolvable) => counter.stats.nodes_per_depth.push(1),
+        }
+        (counter.solution, counter.stats)
+    }
+
+    // Guesses like the default search, but visits the nodes one at a time and reports them to `visitor`
+    // together with the guesses leading to them. Returns `false`, if the visitor stopped the search.
+    fn visit_tree(mut self, guesses: &mut Vec<Candidate>, visitor: &mut impl TreeVisitor) -> bool {
+        visitor.node(guesses);
+        if self.find_naked_singles().is_err() || self.deduce().is_err() {
+            return visitor.contradiction(guesses);
+        }
+        if self.is_solved() {
+            return visitor.solution(self.extract_solution());
+        }
+
+        let state = SolverState { solver: &self };
+        let cell = DefaultGuessStrategy.select_cell(&state);
+        let band = cell.as_index() / 27;
+        let cell_mask = 1 << (cell.as_index() % 27);
+        for digit in state.candidates(cell) {
+            let mut solver = self;
+            solver.insert_candidate_by_mask(digit.as_index() * 3 + band, cell_mask);
+            guesses.push(Candidate { cell, digit });
+            if !solver.visit_tree(guesses, visitor) {
+                return false;
+            }
+            guesses.pop();
+        }
+        true
+    }
+
+    // Deduces entries until a guess is necessary. Unlike `_solve`, this doesn't count towards a limit.
+    fn deduce(&mut self) -> Result<(), Unsolvable> {
+        // only used for the limit check in `_solve`
+        let mut solutions = Solutions::new(Found::Count(0));
+        self._solve(1, &mut solutions)
+    }
+
+    fn _smallest_solutions(&mut self, limit: usize, solutions: &mut Vec<Sudoku>) {
+        if self.deduce().is_err() {
+            return;
+        }
+        self.guess_first_cell(limit, solutions);
+    }
+
+    /// Try all candidates of the first unsolved cell in ascending order and
+    /// collect solutions until `limit` are found.
+    //
+    // All cells before the first unsolved one are the same for every solution
+    // below this point in the search tree. Always branching on the first unsolved cell with
+    // the smallest digit first therefore finds solutions in lexicographical order.
+    fn guess_first_cell(&mut self, limit: usize, solutions: &mut Vec<Sudoku>) {
+        if self.is_solved() {
+            solutions.push(self.extract_solution());
+            return;
+        }
+        let (band, unsolved_cell) =
+            match (0..3).find_map(|band| Some((band, mask_iter(self.unsolved_cells[band]).next()?))) {
+                Some(cell) => cell,
+                None => return,
+            };
+
+        let mut subband = band;
+        while subband < 27 {
+            if self.poss_cells[subband] & unsolved_cell != NONE {
+                let mut solver = *self;
+                solver.insert_candidate_by_mask(subband, unsolved_cell);
+                solver._smallest_solutions(limit, solutions);
+                if solutions.len() == limit {
+                    return;
+                }
+            }
+            subband += 3;
+        }
+    }
+
+    /// Like `guess`, but the cell to guess on is chosen by `guess_strategy`.
+    fn guess_with_strategy(
+        &mut self,
+        limit: usize,
+        solutions: &mut Solutions,
+        guess_strategy: &mut impl GuessStrategy,
+    ) {
+        if self.is_solved() {
+            self.save_solution(limit, solutions);
+            return;
+        }
+
+        let cell = guess_strategy.select_cell(&SolverState { solver: self });
+        let band = cell.as_index() / 27;
+        let cell_mask = 1 << (cell.as_index() % 27);
+        assert!(
+            self.unsolved_cells[band] & cell_mask != NONE,
+            "GuessStrategy selected solved cell {}",
+            cell.get()
+        );
+
+        let mut subband = band;
+        while subband < 27 {
+            if self.poss_cells[subband] & cell_mask != NONE {
+                let mut solver = *self;
+                solver.insert_candidate_by_mask(subband, cell_mask);
+                if solver._solve(limit, solutions).is_ok() {
+                    solver.guess_with_strategy(limit, solutions, guess_strategy);
+                }
+                if solutions.len() == limit {
+                    return;
+                }
+                self.poss_cells[subband] ^= cell_mask;
+            }
+            subband += 3;
+        }
+    }
+}
+
+impl<const NAKED_SINGLES: bool, const HIDDEN_SINGLES: bool> PassesSolver<NAKED_SINGLES, HIDDEN_SINGLES> {
     fn _solutions_up_to(mut self, limit: usize, solutions: &mut Solutions) {
         if self.find_naked_singles().is_err() {
             return;
@@ -176,6 +908,11 @@ impl SudokuSolver {
         if solutions.len() == limit {
             return Err(Unsolvable); // not really, but it forces a recursion stop
         }
+        if solutions.nodes_left == 0 {
+            solutions.out_of_budget = true;
+            return Err(Unsolvable); // same as above
+        }
+        solutions.nodes_left -= 1;
         loop {
             self.find_locked_candidates_and_update()?;
             if self.is_solved() {
@@ -221,7 +958,10 @@ impl SudokuSolver {
             self.pairs[band] = cells2 ^ cells3;
 
             // new singles, ignore previously solved ones
-            let singles = (cells1 ^ cells2) & self.unsolved_cells[band];
+            let singles = match NAKED_SINGLES {
+                true => (cells1 ^ cells2) & self.unsolved_cells[band],
+                false => NONE,
+            };
 
             'singles: for cell_mask_single in mask_iter(singles) {
                 single_applied = true;
@@ -307,6 +1047,11 @@ impl SudokuSolver {
         self.poss_cells[ns1] &= nonconflicting_other;
         self.poss_cells[ns2] &= nonconflicting_other;
 
+        // entered cells are marked as solved on insertion instead
+        if !HIDDEN_SINGLES {
+            return Ok(());
+        }
+
         // Minirows that are locked have no neighboring minirows in the same
         // row or the same box.
         // If they are inside a box where only 1 column is possible, then only 1 cell is possible
@@ -320,9 +1065,15 @@ impl SudokuSolver {
         let locked_candidates_intersection = locked_minirows(shrink) & column_single(poss_cols);
         let solved_rows = shrink_mask(locked_candidates_intersection);
         let solved_cells = row_mask(solved_rows) & poss_cells;
+        self.mark_solved(subband, solved_cells);
 
-        // -------------- jczsolve equivalent: upwcl ---------------------------
-        // Delete candidates of other digits from all solved cells in current subband.
+        Ok(())
+    }
+
+    /// Delete candidates of other digits from all solved cells in the subband.
+    // jczsolve equivalent: upwcl
+    #[inline(always)]
+    fn mark_solved(&mut self, subband: usize, solved_cells: u32) {
         let band = subband % 3;
         let nonconflicting_cells = !solved_cells;
         self.unsolved_cells[band] &= nonconflicting_cells;
@@ -334,31 +1085,45 @@ impl SudokuSolver {
             }
             other_subband += 3;
         }
-        // ----------------------- end upwcl -----------------------------------
-
-        Ok(())
     }
 
     // jczsolve equivalent: Guess
     fn guess(&mut self, limit: usize, solutions: &mut Solutions) {
         if self.is_solved() {
-            debug_assert!(solutions.len() < limit);
-            match solutions {
-                Solutions::Count(count) => *count += 1,
-                Solutions::Vector(vec) => vec.push(self.extract_solution()),
-                Solutions::Buffer(buf, len) => {
-                    if let Some(sudoku_slot) = buf.get_mut(*len) {
-                        *sudoku_slot = self.extract_solution().to_bytes();
-                    }
-                    *len += 1;
-                }
-            }
+            self.save_solution(limit, solutions);
         } else if self.guess_bivalue_in_cell(limit, solutions).is_ok() {
             // .is_ok() == found nothing
             self.guess_some_cell(limit, solutions);
         }
     }
 
+    fn save_solution(&self, limit: usize, solutions: &mut Solutions) {
+        debug_assert!(solutions.len() < limit);
+        match &mut solutions.found {
+            Found::Count(count) => *count += 1,
+            Found::Callback(f, len) => {
+                *len += 1;
+                if !f(&self.extract_solution()) {
+                    // the search stops at the next node
+                    solutions.nodes_left = 0;
+                }
+            }
+            Found::Nth(n, len, solution) => {
+                if *len == *n {
+                    *solution = Some(self.extract_solution());
+                }
+                *len += 1;
+            }
+            Found::Vector(vec) => vec.push(self.extract_solution()),
+            Found::Buffer(buf, len) => {
+                if let Some(sudoku_slot) = buf.get_mut(*len) {
+                    *sudoku_slot = self.extract_solution().to_bytes();
+                }
+                *len += 1;
+            }
+        }
+    }
+
     /// Find some cell with only 2 possible values and try both in order.
     //
     // Whenever a guess has to be taken, there is virtually always a cell
@@ -418,18 +1183,7 @@ impl SudokuSolver {
     //                      jczsolve picks the first unsolved cell it can find
     //                      This fn checks up to 3 cells as explained above
     fn guess_some_cell(&mut self, limit: usize, solutions: &mut Solutions) {
-        let best_guess = (0..3)
-            .flat_map(|band| {
-                // get first unsolved cell, if it exists
-                let one_unsolved_cell = mask_iter(self.unsolved_cells[band]).next()?;
-                let n_candidates = (0..9)
-                    .map(|offset| band + 3 * offset)
-                    .filter(|&subband| self.poss_cells[subband] & one_unsolved_cell != NONE)
-                    .count();
-                Some((n_candidates, band, one_unsolved_cell))
-            })
-            .min();
-        let (_, band, unsolved_cell) = match best_guess {
+        let (_, band, unsolved_cell) = match self.min_candidates_cell_of_bands() {
             Some(min) => min,
             None => return,
         };
@@ -453,6 +1207,23 @@ impl SudokuSolver {
         }
     }
 
+    /// Find the first unsolved cell in each band and return the one with the fewest candidates
+    /// as the tuple (candidate count, band, cell mask).
+    #[inline(always)]
+    fn min_candidates_cell_of_bands(&self) -> Option<(usize, usize, u32)> {
+        (0..3)
+            .flat_map(|band| {
+                // get first unsolved cell, if it exists
+                let one_unsolved_cell = mask_iter(self.unsolved_cells[band]).next()?;
+                let n_candidates = (0..9)
+                    .map(|offset| band + 3 * offset)
+                    .filter(|&subband| self.poss_cells[subband] & one_unsolved_cell != NONE)
+                    .count();
+                Some((n_candidates, band, one_unsolved_cell))
+            })
+            .min()
+    }
+
     /// Insert a candidate by cell and digit.
     /// Removes all conflicting candidates.
     //
@@ -509,6 +1280,29 @@ impl SudokuSolver {
         let cell = band * 27 + bit_pos(mask);
 
         self.poss_cells[subband] &= nonconflicting_cells_same_band(cell);
+        if !HIDDEN_SINGLES {
+            // usually done by the update, together with the hidden singles of the row
+            self.mark_solved(subband, mask);
+        }
+    }
+
+    /// Extract the digits of all solved cells and the candidates of all unsolved cells.
+    /// Must only be called after a full update, so that solved cells have no leftover candidates.
+    fn extract_grid_and_candidates(&self) -> (Sudoku, [Set<Digit>; 81]) {
+        let mut sudoku = [0; 81];
+        let mut candidates = [Set::NONE; 81];
+        for (subband, &mask) in (0..27).zip(self.poss_cells.0.iter()) {
+            let digit = Digit::from_index(subband as u8 / 3);
+            let band = subband % 3;
+            for cell_mask in mask_iter(mask) {
+                let cell = band * 27 + bit_pos(cell_mask);
+                match self.unsolved_cells[band] & cell_mask != NONE {
+                    true => candidates[cell] |= digit,
+                    false => sudoku[cell] = digit.get(),
+                }
+            }
+        }
+        (Sudoku(sudoku), candidates)
     }
 
     /// Extract the digits of a solved sudoku from the bitmasks of the solver.
@@ -12,10 +12,16 @@
 //! slower than the fast solver.
 
 pub mod deduction;
+mod difficulty;
+pub(crate) mod rating;
 mod solver;
+pub(crate) mod stats;
 mod strategies;
 pub(crate) mod utils;
 
 pub use self::deduction::Deduction;
+pub use self::difficulty::Difficulty;
+pub use self::rating::RatingReport;
 pub use self::solver::StrategySolver;
+pub use self::stats::SolveStats;
 pub use self::strategies::Strategy;
@@ -119,6 +119,24 @@ pub enum Deduction<T> {
         pincers: Set<Cell>,
         conflicts: T,
     },
+    /// Result of [`WWing`](super::Strategy::WWing)
+    WWing {
+        /// The 2 bivalue cells with the same 2 candidates
+        cells: Set<Cell>,
+        /// The digit of the strong link connecting the bivalue cells
+        link_digit: Digit,
+        /// The house in which `link_digit` is only possible in 2 cells, each seeing one of the bivalue cells
+        link_house: House,
+        conflicts: T,
+    },
+    /// Result of [`UniqueRectangles`](super::Strategy::UniqueRectangles)
+    UniqueRectangle {
+        /// The 4 cells forming the rectangle. They always occupy 2 blocks in one chute.
+        cells: Set<Cell>,
+        /// The 2 digits that would form a deadly pattern
+        digits: Set<Digit>,
+        conflicts: T,
+    },
     AvoidableRectangle {
         /// The 2 rows and 2 columns forming the avoidable rectangle. The cells where they overlap always occupy 2 blocks in one chute.
         lines: Set<Line>,
@@ -191,6 +209,8 @@ impl Deduction<&'_ [Candidate]> {
                 3 => Strategy::XyzWing,
                 _ => unreachable!(),
             },
+            WWing { .. } => Strategy::WWing,
+            UniqueRectangle { .. } => Strategy::UniqueRectangles,
             AvoidableRectangle { .. } => unimplemented!(),
         }
     }
@@ -235,6 +255,18 @@ impl _Deduction {
             }
             => Wing { hinge, hinge_digits, pincers, conflicts: &eliminated[conflicts] },
 
+            WWing {
+                cells, link_digit, link_house,
+                conflicts
+            }
+            => WWing { cells, link_digit, link_house, conflicts: &eliminated[conflicts] },
+
+            UniqueRectangle {
+                cells, digits,
+                conflicts
+            }
+            => UniqueRectangle { cells, digits, conflicts: &eliminated[conflicts] },
+
             AvoidableRectangle { .. } => unimplemented!(),
             //SinglesChain(x) => SinglesChain(&eliminated[x]),
         }
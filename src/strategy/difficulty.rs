@@ -0,0 +1,57 @@
+//! Coarse difficulty grading based on the strategies needed to solve a sudoku
+
+use super::{Strategy, StrategySolver};
+use crate::Sudoku;
+
+/// Difficulty classes of sudokus, ordered from easiest to hardest.
+///
+/// A sudoku's difficulty is determined by the hardest [`Strategy`] that the [`StrategySolver`] needs
+/// to solve it. See [`Strategy::difficulty`] for the classification of strategies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+    /// Solvable with naked and hidden singles only
+    Easy,
+    /// Requires locked candidates or naked or hidden pairs or triples
+    Medium,
+    /// Requires fish, wings, quads or unique rectangles
+    Hard,
+    /// Can't be solved with the strategies available to the [`StrategySolver`].
+    /// Such sudokus need more advanced strategies or guessing.
+    Expert,
+}
+
+impl Difficulty {
+    /// Grades the sudoku by solving it with all strategies supported for grading.
+    /// Returns `None`, if the sudoku doesn't have a unique solution.
+    ///
+    /// This is much slower than solving the sudoku with [`Sudoku::solution`].
+    ///
+    /// ```
+    /// use sudoku::Sudoku;
+    /// use sudoku::strategy::Difficulty;
+    ///
+    /// let sudoku = Sudoku::from_str_line(
+    ///     "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(Difficulty::of(sudoku), Some(Difficulty::Medium));
+    /// ```
+    pub fn of(sudoku: Sudoku) -> Option<Difficulty> {
+        if !sudoku.is_uniquely_solvable() {
+            return None;
+        }
+
+        // The solver always returns to the easiest strategies after a successful deduction,
+        // so the hardest strategy used is also the hardest one needed.
+        let difficulty = match StrategySolver::from_sudoku(sudoku).solve(Strategy::ALL) {
+            Ok((_, deductions)) => deductions
+                .iter()
+                .map(|deduction| deduction.strategy().difficulty())
+                .max()
+                .unwrap_or(Difficulty::Easy),
+            Err(_) => Difficulty::Expert,
+        };
+        Some(difficulty)
+    }
+}
@@ -0,0 +1,43 @@
+//! Cross-check of the strategy-based grading against the backtracking solver
+
+use super::Difficulty;
+use crate::Sudoku;
+
+/// Grading of a sudoku both by the strategies it needs and by the guesses of the backtracking solver,
+/// returned by [`Sudoku::rating_report`].
+///
+/// Before it guesses, the backtracking solver deduces as much as it can with naked singles and a band-wise
+/// variant of locked candidates, which roughly corresponds to [`Difficulty::Medium`].
+/// The two gradings disagree in two ways:
+/// - A [`Difficulty::Easy`] sudoku needed guesses. The backtracking solver doesn't search for all hidden singles,
+///   so this happens for a small share of easy sudokus.
+/// - A [`Difficulty::Hard`] or [`Difficulty::Expert`] sudoku needed no guesses. The deductions of the
+///   backtracking solver go beyond the easier strategies here, which points to a gap in the strategies used for grading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RatingReport {
+    /// Difficulty according to the strategies needed, see [`Difficulty::of`]
+    pub difficulty: Difficulty,
+    /// Number of guesses the backtracking solver made until it found the solution,
+    /// i.e. the number of nodes of its search tree apart from the root. See [`Sudoku::solve_with_tree_stats`].
+    pub guesses: u64,
+}
+
+impl RatingReport {
+    /// Checks whether both gradings agree, i.e. neither of the disagreements explained above occurred.
+    pub fn is_consistent(&self) -> bool {
+        match self.difficulty {
+            Difficulty::Easy => self.guesses == 0,
+            Difficulty::Medium => true,
+            Difficulty::Hard | Difficulty::Expert => self.guesses != 0,
+        }
+    }
+}
+
+pub(crate) fn rating_report(sudoku: Sudoku) -> Option<RatingReport> {
+    let difficulty = Difficulty::of(sudoku)?;
+    let (_, tree_stats) = sudoku.solve_with_tree_stats();
+    Some(RatingReport {
+        difficulty,
+        guesses: tree_stats.total_nodes() - 1,
+    })
+}
@@ -934,6 +934,64 @@ impl StrategySolver {
         )
     }
 
+    pub(crate) fn find_w_wing(&mut self, stop_after_first: bool) -> Result<(), Unsolvable> {
+        self.update_cell_poss_house_solved()?;
+        let cell_poss_digits = &self.cell_poss_digits.state;
+        let eliminated_entries = &mut self.eliminated_entries;
+        let deductions = &mut self.deductions;
+
+        w_wing::find_w_wing(
+            cell_poss_digits,
+            stop_after_first,
+            |[cell1, cell2], link_digit, link_house, elim_digit| {
+                let common_neighbors = cell1.neighbors_set() & cell2.neighbors_set();
+
+                let conflicts = common_neighbors
+                    .into_iter()
+                    .filter(|&cell| cell_poss_digits[cell].contains(elim_digit))
+                    .map(|cell| Candidate {
+                        cell,
+                        digit: elim_digit,
+                    });
+
+                let on_conflict = |conflicts| Deduction::WWing {
+                    cells: cell1.as_set() | cell2,
+                    link_digit,
+                    link_house,
+                    conflicts,
+                };
+
+                Self::enter_conflicts(eliminated_entries, deductions, conflicts, on_conflict)
+            },
+        )
+    }
+
+    pub(crate) fn find_unique_rectangles(&mut self, stop_after_first: bool) -> Result<(), Unsolvable> {
+        self.update_cell_poss_house_solved()?;
+        let cell_poss_digits = &self.cell_poss_digits.state;
+        let eliminated_entries = &mut self.eliminated_entries;
+        let deductions = &mut self.deductions;
+
+        unique_rectangles::find_unique_rectangles(
+            cell_poss_digits,
+            stop_after_first,
+            |rectangle, digits, free_cell| {
+                let conflicts = digits.into_iter().map(|digit| Candidate {
+                    cell: free_cell,
+                    digit,
+                });
+
+                let on_conflict = |conflicts| Deduction::UniqueRectangle {
+                    cells: rectangle,
+                    digits,
+                    conflicts,
+                };
+
+                Self::enter_conflicts(eliminated_entries, deductions, conflicts, on_conflict)
+            },
+        )
+    }
+
     pub(crate) fn find_xyz_wing(&mut self, stop_after_first: bool) -> Result<(), Unsolvable> {
         self.update_cell_poss_house_solved()?;
         let cell_poss_digits = &self.cell_poss_digits.state;
@@ -0,0 +1,56 @@
+//! Counts of cells placed by deduction and by guessing
+
+use super::deduction::Deduction;
+use super::{Strategy, StrategySolver};
+use crate::Sudoku;
+
+/// How the empty cells of a sudoku were filled in by [`Sudoku::solve_with_stats`].
+///
+/// The sudoku is solved with naked and hidden singles as far as possible. Whenever it gets stuck,
+/// the solution's entry is guessed for the empty cell with the fewest candidates and solving continues.
+/// Only guesses that are part of the solution are made, so guesses never have to be undone.
+/// The counts add up to the number of empty cells of the sudoku.
+///
+/// The share of guessed cells is a cheap indicator of how hard a sudoku is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SolveStats {
+    /// Cells filled in as naked singles
+    pub singles_placed: u8,
+    /// Cells filled in as hidden singles
+    pub hidden_singles_placed: u8,
+    /// Cells filled in by guessing
+    pub guessed_placed: u8,
+}
+
+pub(crate) fn solve_with_stats(sudoku: Sudoku) -> Option<(Sudoku, SolveStats)> {
+    const SINGLES: &[Strategy] = &[Strategy::NakedSingles, Strategy::HiddenSingles];
+
+    let solution = sudoku.solution()?;
+    let mut stats = SolveStats::default();
+    let mut grid = sudoku;
+    loop {
+        let (partial, deductions) = match StrategySolver::from_sudoku(grid).solve(SINGLES) {
+            Ok((solved, deductions)) => (solved, deductions),
+            Err((partial, deductions)) => (partial, deductions),
+        };
+        for deduction in deductions.iter() {
+            match deduction {
+                Deduction::NakedSingles(_) => stats.singles_placed += 1,
+                Deduction::HiddenSingles(..) => stats.hidden_singles_placed += 1,
+                _ => unreachable!("only singles are searched for"),
+            }
+        }
+        if partial.is_solved() {
+            return Some((solution, stats));
+        }
+
+        let candidate_counts = partial.candidate_counts();
+        let cell = (0..81)
+            .filter(|&cell| partial.0[cell] == 0)
+            .min_by_key(|&cell| candidate_counts[cell])
+            .unwrap();
+        grid = partial;
+        grid.0[cell] = solution.0[cell];
+        stats.guessed_placed += 1;
+    }
+}
@@ -9,10 +9,12 @@ pub(crate) mod locked_candidates;
 pub(crate) mod mutant_fish;
 pub(crate) mod naked_singles;
 pub(crate) mod naked_subsets;
+pub(crate) mod unique_rectangles;
+pub(crate) mod w_wing;
 pub(crate) mod xy_wing;
 pub(crate) mod xyz_wing;
 
-use super::StrategySolver;
+use super::{Difficulty, StrategySolver};
 use crate::helper::Unsolvable;
 
 /// The strategies that can be used to find hints, solve or grade a sudoku.
@@ -37,15 +39,18 @@ pub enum Strategy {
     Jellyfish,
     XyWing,
     XyzWing,
+    /// W-Wing: 2 bivalue cells with the same candidates that are connected by a strong link
+    WWing,
     MutantSwordfish,
     MutantJellyfish,
+    /// Unique Rectangle Type 1. Assumes that the sudoku has a unique solution.
+    UniqueRectangles,
     AvoidableRectangles,
     //SinglesChain,
 }
 
 impl Strategy {
-    /// Set of all available strategies, for test purposes
-    #[allow(unused)]
+    /// Set of all available strategies, ordered by difficulty. Used for tests and grading.
     #[rustfmt::skip]
     pub(crate) const ALL: &'static [Strategy] = &[
                                     // difficulty as assigned by
@@ -61,12 +66,25 @@ impl Strategy {
         Strategy::HiddenTriples,    // 40
         Strategy::XyWing,           // 42
         Strategy::XyzWing,          // 44
+        Strategy::WWing,            // 44, not in SudokuExplainer, rated like XyzWing
+        Strategy::UniqueRectangles, // 45
         Strategy::NakedQuads,       // 50
         Strategy::Jellyfish,        // 52
         Strategy::HiddenQuads,      // 54
         //Strategy::SinglesChain,
     ];
 
+    /// Returns the difficulty class of sudokus whose solution requires this strategy.
+    pub fn difficulty(&self) -> Difficulty {
+        use self::Strategy::*;
+        match *self {
+            NakedSingles | HiddenSingles => Difficulty::Easy,
+            LockedCandidates | NakedPairs | NakedTriples | HiddenPairs | HiddenTriples => Difficulty::Medium,
+            NakedQuads | HiddenQuads | XWing | Swordfish | Jellyfish | XyWing | XyzWing | WWing
+            | MutantSwordfish | MutantJellyfish | UniqueRectangles | AvoidableRectangles => Difficulty::Hard,
+        }
+    }
+
     // is_first_strategy is an optimization hint
     // it doesn't need to be used
     pub(crate) fn deduce(
@@ -94,13 +112,41 @@ impl Strategy {
             Jellyfish => state.find_jellyfish(stop_after_first),
             XyWing => state.find_xy_wing(stop_after_first),
             XyzWing => state.find_xyz_wing(stop_after_first),
+            WWing => state.find_w_wing(stop_after_first),
             MutantSwordfish => state.find_mutant_fish(3, stop_after_first),
             MutantJellyfish => state.find_mutant_fish(4, stop_after_first),
+            UniqueRectangles => state.find_unique_rectangles(stop_after_first),
             //SinglesChain => state.find_singles_chain(stop_after_first), // TODO: Implement non-eager SinglesChain
             _ => unimplemented!(),
         }
     }
 
+    /// Returns the common name of the strategy, e.g. "Naked Singles" or "X-Wing".
+    pub fn name(&self) -> &'static str {
+        use self::Strategy::*;
+        match *self {
+            NakedSingles => "Naked Singles",
+            HiddenSingles => "Hidden Singles",
+            LockedCandidates => "Locked Candidates",
+            NakedPairs => "Naked Pairs",
+            NakedTriples => "Naked Triples",
+            NakedQuads => "Naked Quads",
+            HiddenPairs => "Hidden Pairs",
+            HiddenTriples => "Hidden Triples",
+            HiddenQuads => "Hidden Quads",
+            XWing => "X-Wing",
+            Swordfish => "Swordfish",
+            Jellyfish => "Jellyfish",
+            XyWing => "XY-Wing",
+            XyzWing => "XYZ-Wing",
+            WWing => "W-Wing",
+            MutantSwordfish => "Mutant Swordfish",
+            MutantJellyfish => "Mutant Jellyfish",
+            UniqueRectangles => "Unique Rectangles",
+            AvoidableRectangles => "Avoidable Rectangles",
+        }
+    }
+
     pub(crate) fn deduce_one(&self, state: &mut StrategySolver) -> Result<(), Unsolvable> {
         self.deduce(state, true, false)
     }
@@ -0,0 +1,107 @@
+use super::prelude::*;
+
+pub(crate) fn find_unique_rectangles(
+    cell_poss_digits: &CellArray<Set<Digit>>,
+    stop_after_first: bool,
+    mut on_unique_rectangle: impl FnMut(
+        // 4 cells of the rectangle
+        Set<Cell>,
+        // 2 digits of the deadly pattern
+        Set<Digit>,
+        // the one cell with additional candidates
+        Cell,
+    ) -> bool,
+) -> Result<(), Unsolvable> {
+    let cell = |row: u8, col: u8| Cell::new(row * 9 + col);
+    for row1 in 0..8 {
+        for row2 in row1 + 1..9 {
+            let rows_in_same_chute = row1 / 3 == row2 / 3;
+            for col1 in 0..8 {
+                for col2 in col1 + 1..9 {
+                    let cols_in_same_chute = col1 / 3 == col2 / 3;
+                    // the rectangle must occupy exactly 2 blocks
+                    if !(rows_in_same_chute ^ cols_in_same_chute) {
+                        continue;
+                    }
+
+                    let cells = [
+                        cell(row1, col1),
+                        cell(row1, col2),
+                        cell(row2, col1),
+                        cell(row2, col2),
+                    ];
+
+                    // 3 cells must be bivalue cells with the same 2 candidates
+                    // and the remaining cell must contain both and at least one more
+                    for (i, &free_cell) in cells.iter().enumerate() {
+                        let mut floor_cells = cells.iter().enumerate().filter(|&(j, _)| i != j);
+                        let (_, &first) = floor_cells.next().unwrap();
+                        let digits = cell_poss_digits[first];
+                        if digits.len() != 2
+                            || !floor_cells.all(|(_, &cell)| cell_poss_digits[cell] == digits)
+                        {
+                            continue;
+                        }
+
+                        let candidates_free_cell = cell_poss_digits[free_cell];
+                        if candidates_free_cell.len() > 2 && candidates_free_cell.contains(digits) {
+                            let rectangle = cells.iter().fold(Set::NONE, |set, &cell| set | cell);
+                            if on_unique_rectangle(rectangle, digits, free_cell) && stop_after_first {
+                                return Ok(());
+                            }
+                        }
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strategy::{Deduction, Strategy, StrategySolver};
+
+    #[test]
+    fn unique_rectangle_type_1() {
+        let sudoku = Sudoku::from_str_line(
+            ".8..5.1.7..694...5.........5..3.9.7.62..7..54.7.4.5..3.........2...375..3.8.1..6.",
+        )
+        .unwrap();
+        let strategies = [
+            Strategy::NakedSingles,
+            Strategy::HiddenSingles,
+            Strategy::LockedCandidates,
+        ];
+        assert!(StrategySolver::from_sudoku(sudoku).solve(&strategies).is_err());
+
+        let strategies = [&strategies[..], &[Strategy::UniqueRectangles]].concat();
+        let (solution, deductions) = StrategySolver::from_sudoku(sudoku).solve(&strategies).unwrap();
+        assert_eq!(Some(solution), sudoku.solution());
+
+        let (cells, digits, conflicts) = deductions
+            .iter()
+            .find_map(|deduction| match deduction {
+                Deduction::UniqueRectangle {
+                    cells,
+                    digits,
+                    conflicts,
+                } => Some((cells, digits, conflicts)),
+                _ => None,
+            })
+            .expect("No UniqueRectangle found.");
+
+        let expected_cells = [31, 33, 49, 51]
+            .iter()
+            .fold(Set::NONE, |set, &cell| set | Cell::new(cell));
+        assert_eq!(cells, expected_cells);
+        assert_eq!(digits, Digit::new(2).as_set() | Digit::new(6));
+        let conflict = |digit| Candidate {
+            cell: Cell::new(33),
+            digit: Digit::new(digit),
+        };
+        assert_eq!(conflicts, &[conflict(2), conflict(6)]);
+    }
+}
@@ -0,0 +1,104 @@
+use super::prelude::*;
+
+// A W-Wing consists of 2 bivalue cells with the same 2 candidates {X, Y} that don't see each other
+// and a strong link on Y, i.e. a house in which Y is possible in only 2 cells, where each of the
+// link cells sees a different one of the bivalue cells.
+// One of the bivalue cells must be X: If neither was, both would be Y and eliminate Y from
+// both ends of the strong link. Therefore, X can be eliminated from all cells seeing both.
+pub(crate) fn find_w_wing(
+    cells_poss_digits: &CellArray<Set<Digit>>,
+    stop_after_first: bool,
+    mut on_w_wing: impl FnMut(
+        [Cell; 2], // bivalue cells
+        Digit,     // digit of the strong link
+        House,     // house of the strong link
+        Digit,     // digit to eliminate
+    ) -> bool,
+) -> Result<(), Unsolvable> {
+    let bivalue_cells = || Cell::all().filter(|&cell| cells_poss_digits[cell].len() == 2);
+
+    for cell1 in bivalue_cells() {
+        let digits = cells_poss_digits[cell1];
+        let neighbors1 = cell1.neighbors_set();
+
+        for cell2 in bivalue_cells().filter(|&cell2| cell2 > cell1) {
+            if cells_poss_digits[cell2] != digits || neighbors1.contains(cell2) {
+                continue;
+            }
+            let neighbors2 = cell2.neighbors_set();
+
+            for link_digit in digits {
+                let elim_digit = digits.without(link_digit.as_set()).one_possibility();
+
+                let strong_link = House::all().find(|&house| {
+                    let link_cells = house
+                        .cells()
+                        .into_iter()
+                        .filter(|&cell| cells_poss_digits[cell].contains(link_digit))
+                        .fold(Set::NONE, |link_cells, cell| link_cells | cell);
+                    if link_cells.len() != 2 || link_cells.overlaps(cell1.as_set() | cell2) {
+                        return false;
+                    }
+                    let mut link_cells = link_cells.into_iter();
+                    let (link1, link2) = (link_cells.next().unwrap(), link_cells.next().unwrap());
+                    (neighbors1.contains(link1) && neighbors2.contains(link2))
+                        || (neighbors1.contains(link2) && neighbors2.contains(link1))
+                });
+
+                if let Some(house) = strong_link {
+                    let found_conflicts = on_w_wing([cell1, cell2], link_digit, house, elim_digit);
+                    if found_conflicts && stop_after_first {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::strategy::{Deduction, Strategy, StrategySolver};
+
+    // generated sudoku that can be solved with all strategies, but not without W-Wing
+    #[test]
+    fn w_wing() {
+        let sudoku = Sudoku::from_str_line(
+            "8...4.7.93...8.14...4.....6.....9.68...134...24.5.....6.....5...13.9...44.8.1...3",
+        )
+        .unwrap();
+        let solution = sudoku.solution().unwrap();
+
+        let without_w_wing = Strategy::ALL
+            .iter()
+            .filter(|strategy| !matches!(strategy, Strategy::WWing))
+            .cloned()
+            .collect::<Vec<_>>();
+        assert!(StrategySolver::from_sudoku(sudoku)
+            .solve(&without_w_wing)
+            .is_err());
+
+        let (_, deductions) = StrategySolver::from_sudoku(sudoku).solve(Strategy::ALL).unwrap();
+        let mut n_w_wings = 0;
+        for deduction in deductions.iter() {
+            if let Deduction::WWing {
+                cells,
+                link_digit,
+                link_house,
+                conflicts,
+            } = deduction
+            {
+                n_w_wings += 1;
+                assert_eq!(cells.len(), 2);
+                assert!(!link_house.cells().overlaps(cells));
+                for conflict in conflicts {
+                    assert_ne!(conflict.digit, link_digit);
+                    assert_ne!(solution.0[conflict.cell.as_index()], conflict.digit.get());
+                }
+            }
+        }
+        assert!(n_w_wings > 0);
+    }
+}
@@ -92,6 +92,88 @@ fn from_str_line_with_comment() {
     }
 }
 
+#[test]
+fn from_str_line_with_comment_text() {
+    let line = "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...";
+    let sudoku = Sudoku::from_str_line(line).unwrap();
+    let cases = [
+        (String::from(line), None),
+        (format!("{}\n", line), None),
+        (format!("{}\r\n", line), None),
+        (format!("{} ", line), None),
+        (format!("  {} foo bar", line), Some("foo bar")),
+        (format!("{};source\n", line), Some("source")),
+        (format!("{},\t 0123 ", line), Some("0123")),
+    ];
+    for (input, comment) in &cases {
+        assert_eq!(
+            Sudoku::from_str_line_with_comment(input),
+            Ok((sudoku, *comment)),
+            "{:?}",
+            input
+        );
+    }
+    assert!(Sudoku::from_str_line_with_comment(&line[1..]).is_err());
+}
+
+#[test]
+fn from_reader() {
+    use std::str;
+    use sudoku::errors::{BlockParseError, ReadError};
+
+    let line = "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...";
+    let sudoku = Sudoku::from_str_line(line).unwrap();
+    let block: String = line
+        .as_bytes()
+        .chunks(9)
+        .map(|row| format!("{}\n", str::from_utf8(row).unwrap()))
+        .collect();
+
+    let lines = format!("\n{} comment\n{}\nrest", line, line);
+    let mut input = lines.as_bytes();
+    assert_eq!(Sudoku::from_reader_line(&mut input).unwrap(), sudoku);
+    assert_eq!(Sudoku::from_reader_line(&mut input).unwrap(), sudoku);
+    assert_eq!(input, b"rest");
+
+    let blocks = format!("\n{}\n  \n{}rest", block, block);
+    let mut input = blocks.as_bytes();
+    assert_eq!(Sudoku::from_reader_block(&mut input).unwrap(), sudoku);
+    assert_eq!(Sudoku::from_reader_block(&mut input).unwrap(), sudoku);
+    assert_eq!(input, b"rest");
+
+    let truncated = &block[..block.len() - 10];
+    match Sudoku::from_reader_block(truncated.as_bytes()) {
+        Err(ReadError::Parse(BlockParseError::NotEnoughRows(_))) => {}
+        other => panic!("{:?}", other),
+    }
+    assert!(matches!(
+        Sudoku::from_reader_line(&b""[..]),
+        Err(ReadError::Parse(_))
+    ));
+}
+
+#[test]
+fn from_str_line_surrounding_whitespace() {
+    let sudoku = "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...";
+    let same_sudoku_with_whitespace = [
+        " ...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+        "\t\t...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...  ",
+        "\r\n...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...\n",
+        "  ...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1... comment",
+    ];
+    let sudoku = Sudoku::from_str_line(sudoku).unwrap();
+    for &padded_sudoku in &same_sudoku_with_whitespace[..] {
+        let padded_sudoku = Sudoku::from_str_line(padded_sudoku).unwrap();
+        assert_eq!(sudoku, padded_sudoku);
+    }
+
+    // interior whitespace still ends the sudoku
+    assert!(Sudoku::from_str_line(
+        "  ...2...633 ....54.1..1..398........9....538....3........263..5..5.37....847...1..."
+    )
+    .is_err());
+}
+
 #[test]
 #[should_panic]
 fn wrong_format_1() {
@@ -267,140 +349,2605 @@ fn generate_sudoku_uniqueness() {
     }
 }
 
-// this test is probabilistic in nature
-// if an error occurs, note down the sudoku that it generated
 #[test]
-fn shuffle_unsolved() {
-    let sudoku = Sudoku::generate();
-    test_shuffle_sudoku(sudoku);
+fn is_empty_and_n_empty() {
+    let mut bytes = [0; 81];
+    assert!(Sudoku::from_bytes(bytes).unwrap().is_empty());
+    assert_eq!(Sudoku::from_bytes(bytes).unwrap().n_empty(), 81);
+    bytes[80] = 9;
+    assert!(!Sudoku::from_bytes(bytes).unwrap().is_empty());
+    assert_eq!(Sudoku::from_bytes(bytes).unwrap().n_empty(), 80);
 }
 
-// this test is probabilistic in nature
-// if an error occurs, note down the sudoku that it generated
 #[test]
-fn shuffle_solved() {
-    let sudoku = Sudoku::generate_solved();
-    test_shuffle_sudoku(sudoku);
+fn candidate_counts() {
+    let sudoku = Sudoku::from_str_line(
+        "12345678.........................................................................",
+    )
+    .unwrap();
+    let counts = sudoku.candidate_counts();
+    assert_eq!(counts[..8], [0; 8]);
+    // only 9 is left in the first row
+    assert_eq!(counts[8], 1);
+    // the first two blocks contain 3 digits each, the last one only 2
+    assert_eq!(counts[9..18], [6, 6, 6, 6, 6, 6, 7, 7, 7]);
+    // below the first band, only the column conflicts
+    assert_eq!(counts[27..36], [8, 8, 8, 8, 8, 8, 8, 8, 9]);
+
+    let solved = sudoku.some_solution().unwrap();
+    assert_eq!(solved.candidate_counts(), [0; 81]);
 }
 
-// test if any two sudokus are equal
-// also asserts that the number of clues don't change
-fn test_shuffle_sudoku(sudoku: Sudoku) {
-    let mut sudokus = std::iter::repeat(sudoku)
-        .map(Sudoku::shuffled)
-        .take(1000)
-        .collect::<Vec<_>>();
-    let n_clues = sudoku.n_clues();
-    sudokus.sort();
+#[test]
+fn candidate_lists() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let lists = sudoku.candidate_lists();
+    let counts = sudoku.candidate_counts();
+    assert_eq!(lists.len(), 81);
+    for (cell, list) in lists.iter().enumerate() {
+        assert_eq!(list.len(), counts[cell] as usize);
+        assert!(list.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(list.iter().all(|&digit| sudoku
+            .candidates_for(cell as u8)
+            .contains(sudoku::board::Digit::new(digit))));
+    }
+}
 
-    let mut duplicates = vec![];
-    for (i, sudokus) in sudokus.windows(2).enumerate() {
-        if let [sudoku1, sudoku2] = sudokus {
-            assert_eq!(sudoku1.n_clues(), n_clues);
-            if sudoku1 == sudoku2 {
-                duplicates.push(i);
-            }
-        } else {
-            unreachable!();
-        }
+#[test]
+fn packed() {
+    for sudoku in read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt")) {
+        assert_eq!(Sudoku::from_packed(&sudoku.to_packed()).unwrap(), sudoku);
     }
 
-    if !duplicates.is_empty() {
-        for i in duplicates {
-            println!("sudoku nr {} and next: {}", i, sudokus[i].to_str_line());
-        }
+    let mut packed = [0; 41];
+    packed[3] = 0xA0;
+    assert!(Sudoku::from_packed(&packed).is_err());
+    packed[3] = 0x0A;
+    assert!(Sudoku::from_packed(&packed).is_err());
+    packed[3] = 0;
+    packed[40] = 0x01;
+    assert!(Sudoku::from_packed(&packed).is_err());
+}
 
-        panic!("\nRandomly shuffled a sudoku into the above equal sudoku(s). This is possible, but very unlikely. Please save the sudoku(s) for debugging.");
+#[test]
+fn binary() {
+    use sudoku::errors::FromBinaryError;
+
+    for sudoku in read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt")) {
+        assert_eq!(Sudoku::from_binary(&sudoku.to_binary()).unwrap(), sudoku);
+    }
+
+    let mut binary = Sudoku::from_bytes([0; 81]).unwrap().to_binary();
+    assert!(matches!(
+        Sudoku::from_binary(&binary[..41]),
+        Err(FromBinaryError::WrongLength(41))
+    ));
+    assert!(matches!(
+        Sudoku::from_binary(&[]),
+        Err(FromBinaryError::WrongLength(0))
+    ));
+    binary[5] = 0x0B;
+    assert!(matches!(
+        Sudoku::from_binary(&binary),
+        Err(FromBinaryError::InvalidEntries(_))
+    ));
+    binary[5] = 0;
+    binary[0] = 2;
+    assert!(matches!(
+        Sudoku::from_binary(&binary),
+        Err(FromBinaryError::UnsupportedVersion(2))
+    ));
+}
+
+#[test]
+fn code() {
+    use sudoku::errors::FromCodeError;
+
+    for sudoku in read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt")) {
+        let code = sudoku.to_code();
+        assert_eq!(code.len(), 55);
+        assert!(code
+            .bytes()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == b'-' || ch == b'_'));
+        assert_eq!(Sudoku::from_code(&code).unwrap(), sudoku);
     }
+
+    // all cells 9 is 0x99 for every byte, the padding nibble 0
+    let nines = Sudoku::from_bytes([9; 81]).unwrap();
+    let code = nines.to_code();
+    assert!(code.starts_with("mZmZ"));
+    assert!(code.ends_with("mZA"));
+
+    let code = Sudoku::from_bytes([0; 81]).unwrap().to_code();
+    assert!(matches!(
+        Sudoku::from_code(&code[1..]),
+        Err(FromCodeError::WrongLength(54))
+    ));
+    let bad_char = format!("{}+{}", &code[..3], &code[4..]);
+    assert!(matches!(
+        Sudoku::from_code(&bad_char),
+        Err(FromCodeError::InvalidChar { pos: 3, ch: '+' })
+    ));
+    // padding bits of the last char set
+    let bad_padding = format!("{}B", &code[..54]);
+    assert!(matches!(
+        Sudoku::from_code(&bad_padding),
+        Err(FromCodeError::InvalidChar { pos: 54, ch: 'B' })
+    ));
+    // a nibble of 0xA
+    let bad_entry = format!("o{}", &code[1..]);
+    assert!(matches!(
+        Sudoku::from_code(&bad_entry),
+        Err(FromCodeError::InvalidEntries(_))
+    ));
 }
 
 #[test]
-fn parse_permissive() {
-    let sudokus = [
-        // http://norvig.com/sudoku.html
-        r"
-        4 . . |. . . |8 . 5
-        . 3 . |. . . |. . .
-        . . . |7 . . |. . .
-        ------+------+------
-        . 2 . |. . . |. 6 .
-        . . . |. 8 . |4 . .
-        . . . |. 1 . |. . .
-        ------+------+------
-        . . . |6 . 3 |. 7 .
-        5 . . |2 . . |. . .
-        1 . 4 |. . . |. . .
-        ",
-        // https://projecteuler.net/problem=96
-        r"
-        Grid 01
-        003020600
-        900305001
-        001806400
-        008102900
-        700000008
-        006708200
-        002609500
-        800203009
-        005010300
-        Grid 02
-        200080300
-        060070084
-        030500209
-        000105408
-        000000000
-        402706000
-        301007040
-        720040060
-        004010003
-        ",
+fn ascii_art_with_givens() {
+    let mut bytes = [0; 81];
+    bytes[0] = 1;
+    let puzzle = Sudoku::from_bytes(bytes).unwrap();
+    bytes[80] = 9;
+    let sudoku = Sudoku::from_bytes(bytes).unwrap();
+
+    let art = sudoku.to_ascii_art_with_givens(&puzzle.clues_mask());
+    let lines: Vec<_> = art.lines().collect();
+    assert_eq!(lines.len(), 13);
+    assert_eq!(lines[1], "|[1] .  . | .  .  . | .  .  . |");
+    assert_eq!(lines[11], "| .  .  . | .  .  . | .  .  9 |");
+    assert!(lines.iter().all(|line| line.len() == 31));
+}
+
+#[test]
+fn explainer_format() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let expected = "\
+.-----------------------.---------------------.----------------------.
+| 789     4589   45789  | 2     14789   479   | 7       6      3     |
+| 3       689    2789   | 689   6789    5     | 4       27     1     |
+| 267     456    1      | 46    467     3     | 9       8      257   |
+:-----------------------+---------------------+----------------------:
+| 12678   14568  24578  | 146   12467   2467  | 123678  9      24567 |
+| 12679   1469   2479   | 5     3       8     | 1267    1247   2467  |
+| 126789  3      245789 | 1469  124679  24679 | 12678   12457  24567 |
+:-----------------------+---------------------+----------------------:
+| 189     2      6      | 3     489     49    | 5       147    479   |
+| 5       19     3      | 7     2469    2469  | 126     124    8     |
+| 4       7      89     | 689   25689   1     | 236     23     269   |
+'-----------------------'---------------------'----------------------'
+";
+    assert_eq!(sudoku.to_explainer_format(), expected);
+
+    let empty = Sudoku::from_bytes([0; 81]).unwrap().to_explainer_format();
+    let lines: Vec<&str> = empty.lines().collect();
+    assert_eq!(lines.len(), 13);
+    assert!(lines.iter().all(|line| line.len() == 103));
+    assert_eq!(lines[1].matches("123456789").count(), 9);
+}
+
+#[test]
+fn csv() {
+    use sudoku::errors::CsvParseError;
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let csv = sudoku.to_csv();
+    assert_eq!(csv.lines().next(), Some(",,,2,,,,6,3"));
+    assert_eq!(Sudoku::from_csv(&csv), Ok(sudoku));
+    assert_eq!(Sudoku::from_csv(&csv.replace("\n", "\r\n\n")), Ok(sudoku));
+    assert_eq!(Sudoku::from_csv(&csv.replace(',', " , ")), Ok(sudoku));
+    assert_eq!(Sudoku::from_csv(&csv.replace(",,", ",0,")), Ok(sudoku));
+
+    let mut lines: Vec<String> = csv.lines().map(String::from).collect();
+    lines[4].push(',');
+    assert_eq!(
+        Sudoku::from_csv(&lines.join("\n")),
+        Err(CsvParseError::WrongRowLength { row: 4, len: 10 })
+    );
+    lines[4] = "1,2,3".to_string();
+    assert_eq!(
+        Sudoku::from_csv(&lines.join("\n")),
+        Err(CsvParseError::WrongRowLength { row: 4, len: 3 })
+    );
+    lines[4] = ",,,,,,,,x".to_string();
+    assert_eq!(
+        Sudoku::from_csv(&lines.join("\n")),
+        Err(CsvParseError::InvalidEntry {
+            cell: 44,
+            field: "x".to_string()
+        })
+    );
+    lines[4] = ",,,,,,,,10".to_string();
+    assert!(matches!(
+        Sudoku::from_csv(&lines.join("\n")),
+        Err(CsvParseError::InvalidEntry { cell: 44, .. })
+    ));
+
+    assert_eq!(
+        Sudoku::from_csv(&csv.lines().take(8).collect::<Vec<_>>().join("\n")),
+        Err(CsvParseError::WrongNumberOfRows(8))
+    );
+    assert_eq!(
+        Sudoku::from_csv(&format!("{}{}", csv, csv)),
+        Err(CsvParseError::WrongNumberOfRows(10))
+    );
+}
+
+#[test]
+fn json_array() {
+    use sudoku::errors::JsonParseError;
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let json = sudoku.to_json_array();
+    assert!(json.starts_with("[[0,0,0,2,0,0,0,6,3],[3,0,0,0,0,5,4,0,1],"));
+    assert!(json.ends_with(",[4,7,0,0,0,1,0,0,0]]"));
+    assert_eq!(Sudoku::from_json_array(&json), Ok(sudoku));
+
+    // whitespace, e.g. from pretty printers, is allowed
+    let pretty = json
+        .replace("[[", "[\n  [")
+        .replace("],", "],\n  ")
+        .replace(',', ", ");
+    assert_eq!(Sudoku::from_json_array(&pretty), Ok(sudoku));
+
+    let errors = [
+        ("", JsonParseError::UnexpectedEnd),
+        ("[[1,2", JsonParseError::UnexpectedEnd),
+        ("[[1,2}", JsonParseError::UnexpectedChar { pos: 5, ch: '}' }),
+        ("[[1,-2", JsonParseError::UnexpectedChar { pos: 4, ch: '-' }),
+        ("[[1,12", JsonParseError::InvalidEntry { pos: 4, value: 12 }),
+        ("[[1,2,3]]", JsonParseError::WrongRowLength { row: 0, len: 3 }),
+        (
+            "[[1,2,3,4,5,6,7,8,9,0]]",
+            JsonParseError::WrongRowLength { row: 0, len: 10 },
+        ),
+        ("[[0,0,0,0,0,0,0,0,0]]", JsonParseError::WrongNumberOfRows(1)),
     ];
-    let sudokus_line = [
-        "4.....8.5.3..........7......2.....6.....8.4......1.......6.3.7.5..2.....1.4......",
-        "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..",
+    for &(input, error) in errors.iter() {
+        assert_eq!(Sudoku::from_json_array(input), Err(error), "{}", input);
+    }
+    let trailing = json.clone() + "]";
+    assert_eq!(
+        Sudoku::from_json_array(&trailing),
+        Err(JsonParseError::UnexpectedChar {
+            pos: json.len(),
+            ch: ']'
+        })
+    );
+}
+
+#[test]
+fn complexity_estimate() {
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(empty.complexity_estimate(), 81 * 8 + 27 * 9);
+    assert_eq!(empty.some_solution().unwrap().complexity_estimate(), 0);
+
+    let mean_estimate = |sudokus: Vec<Sudoku>| {
+        let n_sudokus = sudokus.len() as u32;
+        sudokus.iter().map(Sudoku::complexity_estimate).sum::<u32>() / n_sudokus
+    };
+    let easy = read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt"));
+    let hard = read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt"));
+    assert!(mean_estimate(easy) < mean_estimate(hard));
+}
+
+#[test]
+fn generate_partial() {
+    use rand::{rngs::StdRng, SeedableRng};
+    for &n_clues in &[0, 1, 17, 40, 80, 81] {
+        let sudoku = Sudoku::generate_partial(n_clues, &mut StdRng::seed_from_u64(n_clues as u64));
+        assert_eq!(sudoku.n_clues() as usize, n_clues);
+        assert!(sudoku.some_solution().is_some(), "{}", sudoku);
+
+        let same_seed = Sudoku::generate_partial(n_clues, &mut StdRng::seed_from_u64(n_clues as u64));
+        assert_eq!(sudoku, same_seed);
+    }
+}
+
+#[test]
+fn generate_hard() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use sudoku::strategy::Difficulty;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for &min_difficulty in &[Difficulty::Medium, Difficulty::Hard, Difficulty::Expert] {
+        let sudoku = Sudoku::generate_hard(&mut rng, min_difficulty);
+        assert!(Difficulty::of(sudoku).unwrap() >= min_difficulty, "{}", sudoku);
+    }
+
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(Difficulty::of(empty), None);
+}
+
+#[test]
+fn generate_with_clues_retry() {
+    let sudoku = Sudoku::generate_with_clues_retry(30, 5).unwrap();
+    assert_eq!(sudoku.n_clues(), 30);
+    assert!(sudoku.is_uniquely_solvable());
+
+    // practically never reached, the closest puzzle is returned instead
+    let closest = Sudoku::generate_with_clues_retry(17, 2).unwrap();
+    assert!(closest.n_clues() >= 17);
+    assert!(closest.is_uniquely_solvable());
+
+    assert_eq!(Sudoku::generate_with_clues_retry(30, 0), None);
+}
+
+#[test]
+fn is_unique_solution_of() {
+    let solved = Sudoku::from_str_line(
+        "123456789456789123789123456214365897365897214897214365531642978642978531978531642",
+    )
+    .unwrap();
+    assert!(solved.is_unique_solution_of(&solved));
+
+    // 1 and 2 can be swapped in this rectangle
+    let mut grid = solved.to_bytes();
+    for &cell in &[0, 1, 27, 28] {
+        grid[cell] = 0;
+    }
+    let ambiguous = Sudoku::from_bytes(grid).unwrap();
+    assert!(!solved.is_unique_solution_of(&ambiguous));
+
+    grid[27] = 2;
+    let unique = Sudoku::from_bytes(grid).unwrap();
+    assert!(solved.is_unique_solution_of(&unique));
+
+    // solution of a different puzzle
+    let other = Sudoku::generate_solved();
+    assert!(other == solved || !other.is_unique_solution_of(&unique));
+}
+
+#[test]
+fn for_each_cell_mut() {
+    let solved = Sudoku::generate_solved();
+    let mut sudoku = solved;
+    let mut n_calls = 0;
+    // relabel the digits
+    sudoku.for_each_cell_mut(|_, content| {
+        n_calls += 1;
+        *content = content.map(|digit| 10 - digit);
+    });
+    assert_eq!(n_calls, 81);
+    assert!(sudoku.is_solved());
+    assert!(sudoku
+        .iter()
+        .zip(solved.iter())
+        .all(|(a, b)| a.unwrap() + b.unwrap() == 10));
+
+    sudoku.for_each_cell_mut(|_, content| *content = None);
+    assert!(sudoku.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn for_each_cell_mut_invalid_digit() {
+    let mut sudoku = Sudoku::generate_solved();
+    sudoku.for_each_cell_mut(|cell, content| {
+        if cell == 40 {
+            *content = Some(10);
+        }
+    });
+}
+
+#[test]
+fn blank_if() {
+    let solved = Sudoku::generate_solved();
+    let mut sudoku = solved;
+    let mut n_calls = 0;
+    sudoku.blank_if(|cell, digit| {
+        n_calls += 1;
+        cell < 9 || digit == 5
+    });
+    assert_eq!(n_calls, 81);
+    assert!(sudoku.iter().take(9).all(|num| num.is_none()));
+    assert_eq!(sudoku.n_clues(), 81 - 9 - 8);
+    assert!(solved.is_unique_solution_of(&sudoku));
+
+    // empty cells are skipped
+    n_calls = 0;
+    sudoku.blank_if(|_, _| {
+        n_calls += 1;
+        false
+    });
+    assert_eq!(n_calls, 64);
+}
+
+#[test]
+fn is_consistent_with() {
+    let puzzle = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = puzzle.solution().unwrap();
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+
+    assert!(puzzle.is_consistent_with(&puzzle));
+    assert!(puzzle.is_consistent_with(&solution));
+    assert!(!puzzle.is_consistent_with(&empty));
+    assert!(empty.is_consistent_with(&puzzle));
+
+    let mut changed = solution.to_bytes();
+    changed[3] = changed[3] % 9 + 1;
+    assert!(!puzzle.is_consistent_with(&Sudoku::from_bytes(changed).unwrap()));
+
+    // an empty cell of the puzzle may contain anything
+    changed = solution.to_bytes();
+    changed[0] = changed[0] % 9 + 1;
+    assert!(puzzle.is_consistent_with(&Sudoku::from_bytes(changed).unwrap()));
+}
+
+#[test]
+fn generate_balanced() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..10 {
+        let sudoku = Sudoku::generate_balanced(&mut rng);
+        assert!(sudoku.is_uniquely_solvable(), "{}", sudoku);
+
+        let mut counts = [0; 9];
+        for digit in sudoku.iter().flatten() {
+            counts[digit as usize - 1] += 1;
+        }
+        let (min, max) = (counts.iter().min().unwrap(), counts.iter().max().unwrap());
+        assert!(max - min <= 2, "{} {:?}", sudoku, counts);
+    }
+}
+
+#[test]
+fn generate_with_difficulty() {
+    use sudoku::strategy::Difficulty;
+
+    for &difficulty in &[
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Expert,
+    ] {
+        let sudoku = Sudoku::generate_with_difficulty(difficulty);
+        assert_eq!(Difficulty::of(sudoku), Some(difficulty), "{}", sudoku);
+    }
+    assert!(Sudoku::generate_with_difficulty(Difficulty::Easy).n_clues() >= 30);
+}
+
+#[test]
+fn generate_requiring() {
+    use sudoku::strategy::{Difficulty, Strategy, StrategySolver};
+
+    let singles = [Strategy::NakedSingles, Strategy::HiddenSingles];
+    let sudoku = Sudoku::generate_requiring(&singles).unwrap();
+    assert_eq!(Difficulty::of(sudoku), Some(Difficulty::Easy));
+
+    let strategies = [
+        Strategy::NakedSingles,
+        Strategy::HiddenSingles,
+        Strategy::NakedPairs,
     ];
+    let sudoku = Sudoku::generate_requiring(&strategies).unwrap();
+    assert!(StrategySolver::from_sudoku(sudoku).solve(&strategies).is_ok());
+    assert!(StrategySolver::from_sudoku(sudoku).solve(&singles).is_err());
 
-    for (sudoku, line_sudoku) in sudokus.iter().zip(sudokus_line.iter()) {
-        let sudoku1 = Sudoku::from_str_block_permissive(sudoku).expect("permissive block parse error");
-        let sudoku2 = Sudoku::from_str_line(line_sudoku).expect("line parse error");
-        assert!(sudoku1 == sudoku2);
+    // nothing can be solved without entering digits
+    assert_eq!(Sudoku::generate_requiring(&[Strategy::LockedCandidates]), None);
+}
+
+#[test]
+fn generate_with_max_naked_singles() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..10 {
+        let sudoku = Sudoku::generate_with_max_naked_singles(&mut rng, 0);
+        assert!(sudoku.is_uniquely_solvable());
+        let counts = sudoku.candidate_counts();
+        assert!(
+            sudoku
+                .iter()
+                .zip(counts.iter())
+                .all(|(num, &count)| num.is_some() || count > 1),
+            "{}",
+            sudoku
+        );
     }
 }
 
 #[test]
-fn canonicalize() {
-    let sudoku = Sudoku::generate_solved();
-    let (canonical, _) = sudoku.canonicalized().unwrap();
+fn minimal_puzzles() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::HashSet;
 
-    for i in 0..1_000 {
-        let (recanonicalized, _) = sudoku.shuffled().canonicalized().unwrap();
-        if recanonicalized != canonical {
-            panic!("canonicalize(shuffle(canonical_grid)) != canonical_grid on attempt nr {}\nbefore {}\nafter  {}", i, canonical.to_str_line(), recanonicalized.to_str_line());
+    let mut rng = StdRng::seed_from_u64(0);
+    let solved = Sudoku::generate_solved();
+    let puzzles = Sudoku::minimal_puzzles(&solved, &mut rng, 5);
+    assert_eq!(puzzles.len(), 5);
+
+    let canonical: HashSet<_> = puzzles.iter().map(|puzzle| puzzle.canonical_line()).collect();
+    assert_eq!(canonical.len(), 5);
+    for puzzle in puzzles {
+        assert_eq!(puzzle.solution(), Some(solved));
+        for (cell, &num) in puzzle.as_bytes().iter().enumerate() {
+            if num != 0 {
+                let mut bytes = puzzle.to_bytes();
+                bytes[cell] = 0;
+                assert!(!Sudoku::from_bytes(bytes).unwrap().is_uniquely_solvable());
+            }
         }
     }
+
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert!(Sudoku::minimal_puzzles(&empty, &mut rng, 5).is_empty());
 }
 
 #[test]
-fn canonicalize_idempotency() {
-    for _ in 0..1_000 {
-        let (sudoku, _) = Sudoku::generate_solved().canonicalized().unwrap();
-        let (recanonicalized, _) = sudoku.canonicalized().unwrap();
-        if sudoku != recanonicalized {
-            panic!(
-                "canonicalize(canonicalize(sudoku)) != canonicalize(sudoku)\n1. {}\n2. {}",
-                recanonicalized.to_str_line(),
-                sudoku.to_str_line()
+fn block_with_empty_lines_between_rows() {
+    let expected = Sudoku::from_str_line(
+        "__3_2_6__9__3_5__1__18_64____81_29__7_______8__67_82____26_95__8__2_3__9__5_1_3__",
+    )
+    .unwrap();
+    let bare = "__3_2_6__\n9__3_5__1\n__18_64__\n\n__81_29__\n7_______8\n__67_82__\n  \n__26_95__\n8__2_3__9\n__5_1_3__\n\nrest";
+    assert_eq!(Sudoku::from_str_block(bare), Ok(expected));
+
+    let delimited = "__3|_2_|6__\n9__|3_5|__1\n__1|8_6|4__\n\n---+---+---\n\n__8|1_2|9__\n7__|___|__8\n__6|7_8|2__\n---+---+---\n\n__2|6_9|5__\n8__|2_3|__9\n__5|_1_|3__";
+    assert_eq!(Sudoku::from_str_block(delimited), Ok(expected));
+
+    assert_eq!(
+        Sudoku::from_str_block("__3_2_6__\n\n9__3_5__1\n"),
+        Err(sudoku::errors::BlockParseError::NotEnoughRows(3))
+    );
+}
+
+#[test]
+fn transform() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use sudoku::board::Transform;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = sudoku.solution().unwrap();
+
+    assert_eq!(sudoku.with_transform(&Transform::identity()), sudoku);
+    for _ in 0..10 {
+        let transform = Transform::random(&mut rng);
+        let other = Transform::random(&mut rng);
+
+        let transformed = sudoku.with_transform(&transform);
+        assert_eq!(transformed.n_clues(), sudoku.n_clues());
+        assert_eq!(transformed.solution(), Some(solution.with_transform(&transform)));
+        assert_eq!(transformed.canonicalized(), sudoku.canonicalized());
+        assert_eq!(transformed.with_transform(&transform.invert()), sudoku);
+        assert_eq!(transform.then(&transform.invert()), Transform::identity());
+        assert_eq!(
+            sudoku.with_transform(&transform.then(&other)),
+            transformed.with_transform(&other)
+        );
+    }
+
+    // rotation by 90° clockwise
+    let rotation = Transform::identity()
+        .transpose()
+        .swap_cols(0, 2)
+        .swap_cols(3, 5)
+        .swap_cols(6, 8)
+        .swap_stacks(0, 2);
+    let rotated = sudoku.with_transform(&rotation);
+    for row in 0..9 {
+        for col in 0..9 {
+            assert_eq!(
+                rotated.as_bytes()[row * 9 + col],
+                sudoku.as_bytes()[(8 - col) * 9 + row]
             );
         }
     }
 }
 
-#[allow(unused)]
-// as it stands SudokuLine seems to be unnameable because it is not exported
-// compile time check to see if it is constructable and printable
-fn print_line() {
-    let sudoku = Sudoku::from_bytes([0; 81]).unwrap();
-    let line = sudoku.to_str_line();
-    let dereffed_line: &str = &line;
-    println!("{}", line);
+#[test]
+fn has_multiple_solutions() {
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert!(empty.has_multiple_solutions());
+
+    let mut invalid = [0; 81];
+    invalid[0] = 1;
+    invalid[1] = 1;
+    assert!(!Sudoku::from_bytes(invalid).unwrap().has_multiple_solutions());
+
+    let unique = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    assert!(!unique.has_multiple_solutions());
+    assert!(!unique.solution().unwrap().has_multiple_solutions());
+
+    let mut bytes = unique.to_bytes();
+    let first_clue = bytes.iter().position(|&num| num != 0).unwrap();
+    bytes[first_clue] = 0;
+    let sudoku = Sudoku::from_bytes(bytes).unwrap();
+    assert_eq!(
+        sudoku.has_multiple_solutions(),
+        sudoku.solutions_count_up_to(2) == 2
+    );
+}
+
+#[test]
+fn total_candidates() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let total: u32 = sudoku.candidate_counts().iter().map(|&count| count as u32).sum();
+    assert_eq!(sudoku.total_candidates(), total);
+
+    // filling in a cell never adds candidates
+    let solution = sudoku.solution().unwrap();
+    let mut partial = sudoku.to_bytes();
+    partial[0] = solution.to_bytes()[0];
+    let partial = Sudoku::from_bytes(partial).unwrap();
+    assert!(partial.total_candidates() < sudoku.total_candidates());
+}
+
+#[test]
+fn candidates_for() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let counts = sudoku.candidate_counts();
+    for cell in 0..81 {
+        let candidates = sudoku.candidates_for(cell);
+        assert_eq!(candidates.len(), counts[cell as usize]);
+        for digit in candidates {
+            assert!(sudoku.cells_with_candidate(digit.get()).contains(&cell));
+        }
+    }
+
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(empty.candidates_for(40).len(), 9);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn candidates_for_out_of_range() {
+    Sudoku::generate_solved().candidates_for(81);
+}
+
+#[test]
+fn editor() {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use sudoku::board::Editor;
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let mut editor = Editor::from(sudoku);
+    assert_eq!(editor.sudoku(), sudoku);
+    assert_eq!(editor.get(3), Some(2));
+    assert_eq!(editor.get(0), None);
+
+    // random edits, including conflicting ones
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..2000 {
+        let cell = rng.gen_range(0, 81);
+        match rng.gen_range(0, 3) {
+            0 => editor.clear(cell),
+            _ => editor.set(cell, rng.gen_range(1, 10)),
+        }
+        let current = editor.sudoku();
+        for cell in 0..81 {
+            assert_eq!(editor.candidates(cell), current.candidates_for(cell));
+        }
+    }
+
+    for cell in 0..81 {
+        editor.clear(cell);
+    }
+    assert!(Sudoku::from(editor).is_empty());
+    assert!((0..81).all(|cell| editor.candidates(cell).len() == 9));
+}
+
+#[test]
+fn editor_place() {
+    use sudoku::board::Editor;
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = sudoku.solution().unwrap().to_bytes();
+    let mut editor = Editor::new(sudoku);
+    assert!(editor.is_solvable());
+
+    // conflict
+    assert!(!editor.place(0, 2));
+    editor.clear(0);
+    assert!(editor.is_solvable());
+
+    // a candidate that isn't part of the unique solution
+    let wrong = (1..=9)
+        .find(|&digit| {
+            digit != solution[0]
+                && sudoku
+                    .candidates_for(0)
+                    .contains(sudoku::board::Digit::new(digit))
+        })
+        .unwrap();
+    assert!(!editor.place(0, wrong));
+    assert!(!editor.is_solvable());
+    editor.clear(0);
+
+    for cell in 0..81 {
+        if editor.get(cell).is_none() {
+            assert!(editor.place(cell, solution[cell as usize]));
+        }
+    }
+    assert!(editor.sudoku().is_solved());
+
+    // the empty grid can be solved in many ways
+    let mut editor = Editor::new(Sudoku::from_bytes([0; 81]).unwrap());
+    assert!(editor.place(0, 1));
+    assert!(editor.place(40, 1));
+    assert!(!editor.place(8, 1));
+    editor.clear(8);
+    assert!(editor.place(80, 1));
+}
+
+#[test]
+fn cells_with_candidate() {
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(empty.cells_with_candidate(5), (0..81).collect::<Vec<u8>>());
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let counts = sudoku.candidate_counts();
+    let n_candidates: usize = (1..=9)
+        .map(|digit| sudoku.cells_with_candidate(digit).len())
+        .sum();
+    assert_eq!(n_candidates, counts.iter().map(|&n| n as usize).sum::<usize>());
+
+    let solved = sudoku.solution().unwrap();
+    for digit in 1..=9 {
+        for cell in sudoku.cells_with_candidate(digit) {
+            assert_eq!(sudoku.as_bytes()[cell as usize], 0);
+        }
+        assert!(solved.cells_with_candidate(digit).is_empty());
+    }
+}
+
+#[test]
+#[should_panic]
+fn cells_with_candidate_invalid_digit() {
+    Sudoku::from_bytes([0; 81]).unwrap().cells_with_candidate(0);
+}
+
+#[test]
+fn swap_digits() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let mut swapped = sudoku;
+    swapped.swap_digits(3, 8);
+    for (&old, &new) in sudoku.as_bytes().iter().zip(swapped.as_bytes()) {
+        let expected = match old {
+            3 => 8,
+            8 => 3,
+            other => other,
+        };
+        assert_eq!(new, expected);
+    }
+    assert_eq!(
+        swapped.solution().map(|mut s| {
+            s.swap_digits(8, 3);
+            s
+        }),
+        sudoku.solution()
+    );
+
+    swapped.swap_digits(4, 4);
+    swapped.swap_digits(3, 8);
+    assert_eq!(swapped, sudoku);
+}
+
+#[test]
+fn check_answer() {
+    use sudoku::board::AnswerResult;
+
+    let puzzle = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = puzzle.solution().unwrap();
+    assert_eq!(Sudoku::check_answer(&puzzle, &solution), AnswerResult::Correct);
+    assert_eq!(
+        Sudoku::check_answer(&puzzle, &puzzle),
+        AnswerResult::Incomplete(
+            puzzle
+                .as_bytes()
+                .iter()
+                .enumerate()
+                .filter(|(_, &n)| n == 0)
+                .map(|(cell, _)| cell as u8)
+                .collect()
+        )
+    );
+
+    // swapping two entries in a row keeps the row valid, but breaks the columns
+    let mut answer = solution.to_bytes();
+    answer.swap(0, 1);
+    let answer = Sudoku::from_bytes(answer).unwrap();
+    match Sudoku::check_answer(&puzzle, &answer) {
+        AnswerResult::Invalid(conflicts) => {
+            assert!(conflicts.contains(&0) && conflicts.contains(&1));
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    // clue at cell 3
+    let mut answer = solution.to_bytes();
+    answer.swap(3, 4);
+    let answer = Sudoku::from_bytes(answer).unwrap();
+    assert_eq!(
+        Sudoku::check_answer(&puzzle, &answer),
+        AnswerResult::ClueChanged(vec![3])
+    );
+}
+
+#[test]
+fn solve_with_stats() {
+    let easy = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let (solution, stats) = easy.solve_with_stats().unwrap();
+    assert_eq!(Some(solution), easy.solution());
+    assert_eq!(
+        stats.singles_placed + stats.hidden_singles_placed + stats.guessed_placed,
+        easy.n_empty()
+    );
+
+    assert_eq!(
+        solution.solve_with_stats(),
+        Some((solution, sudoku::strategy::SolveStats::default()))
+    );
+
+    // needs guessing
+    let hard = Sudoku::from_str_line(
+        "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4..",
+    )
+    .unwrap();
+    let (_, stats) = hard.solve_with_stats().unwrap();
+    assert!(stats.guessed_placed > 0);
+    assert_eq!(
+        stats.singles_placed + stats.hidden_singles_placed + stats.guessed_placed,
+        hard.n_empty()
+    );
+
+    assert_eq!(Sudoku::from_bytes([0; 81]).unwrap().solve_with_stats(), None);
+}
+
+#[test]
+fn technique_path() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let path = sudoku.technique_path().unwrap();
+    assert!(!path.is_empty());
+    let allowed = [
+        "Naked Singles",
+        "Hidden Singles",
+        "Locked Candidates",
+        "Naked Pairs",
+        "Hidden Pairs",
+    ];
+    assert!(
+        path.iter().all(|name| allowed.contains(&name.as_str())),
+        "{:?}",
+        path
+    );
+
+    assert_eq!(sudoku.solution().unwrap().technique_path(), Some(vec![]));
+    assert_eq!(Sudoku::from_bytes([0; 81]).unwrap().technique_path(), None);
+
+    // needs guessing
+    let hard = Sudoku::from_str_line(
+        "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4..",
+    )
+    .unwrap();
+    assert_eq!(hard.technique_path(), None);
+}
+
+#[test]
+fn solve_one_logical_first() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    assert_eq!(sudoku.solve_one_logical_first(), sudoku.solution());
+
+    // needs guessing
+    let hard = Sudoku::from_str_line(
+        "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4..",
+    )
+    .unwrap();
+    assert_eq!(hard.solve_one_logical_first(), hard.solution());
+
+    // multiple solutions
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert!(empty.solve_one_logical_first().unwrap().is_solved());
+
+    for sudoku in read_sudokus(include_str!("../sudokus/Lines/invalid_sudokus.txt")) {
+        assert_eq!(sudoku.solve_one_logical_first(), None);
+    }
+}
+
+#[test]
+fn all_forced() {
+    use sudoku::strategy::Strategy;
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = sudoku.solution().unwrap().to_bytes();
+    let counts = sudoku.candidate_counts();
+    let forced = sudoku.all_forced();
+    assert!(forced.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    for (cell, digit, strategy) in &forced {
+        assert_eq!(solution[*cell as usize], *digit);
+        match strategy {
+            Strategy::NakedSingles => assert_eq!(counts[*cell as usize], 1),
+            Strategy::HiddenSingles => assert!(counts[*cell as usize] > 1),
+            other => panic!("unexpected strategy {:?}", other),
+        }
+    }
+    // every naked single is found
+    let n_naked = counts.iter().filter(|&&count| count == 1).count();
+    let n_forced_naked = forced
+        .iter()
+        .filter(|(_, _, strategy)| matches!(strategy, Strategy::NakedSingles))
+        .count();
+    assert_eq!(n_forced_naked, n_naked);
+    assert!(forced
+        .iter()
+        .any(|(_, _, strategy)| matches!(strategy, Strategy::HiddenSingles)));
+
+    assert!(Sudoku::generate_solved().all_forced().is_empty());
+    assert!(Sudoku::from_bytes([0; 81]).unwrap().all_forced().is_empty());
+}
+
+#[test]
+fn cell_solve_order() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let order = sudoku.cell_solve_order();
+    for (cell, (step, clue)) in order.iter().zip(sudoku.iter()).enumerate() {
+        assert_eq!(step.is_some(), clue.is_none(), "cell {}", cell);
+    }
+    let mut steps: Vec<u16> = order.iter().filter_map(|&step| step).collect();
+    steps.sort_unstable();
+    steps.dedup();
+    assert_eq!(steps.len(), sudoku.n_empty() as usize);
+
+    assert_eq!(sudoku.solution().unwrap().cell_solve_order(), [None; 81]);
+
+    // needs guessing right away
+    let hard = Sudoku::from_str_line(
+        "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4..",
+    )
+    .unwrap();
+    let n_deduced = hard
+        .cell_solve_order()
+        .iter()
+        .filter(|step| step.is_some())
+        .count();
+    assert!(n_deduced < hard.n_empty() as usize);
+}
+
+#[test]
+fn perturb() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut sudoku = Sudoku::generate();
+    let solution = sudoku.solution().unwrap();
+    for _ in 0..20 {
+        let perturbed = sudoku.perturb(&mut rng).unwrap();
+        assert_ne!(perturbed, sudoku);
+        assert_eq!(perturbed.solution(), Some(solution));
+        sudoku = perturbed;
+    }
+
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(empty.perturb(&mut rng), None);
+}
+
+// this test is probabilistic in nature
+// if an error occurs, note down the sudoku that it generated
+#[test]
+fn shuffle_unsolved() {
+    let sudoku = Sudoku::generate();
+    test_shuffle_sudoku(sudoku);
+}
+
+// this test is probabilistic in nature
+// if an error occurs, note down the sudoku that it generated
+#[test]
+fn shuffle_solved() {
+    let sudoku = Sudoku::generate_solved();
+    test_shuffle_sudoku(sudoku);
+}
+
+// test if any two sudokus are equal
+// also asserts that the number of clues don't change
+fn test_shuffle_sudoku(sudoku: Sudoku) {
+    let mut sudokus = std::iter::repeat(sudoku)
+        .map(Sudoku::shuffled)
+        .take(1000)
+        .collect::<Vec<_>>();
+    let n_clues = sudoku.n_clues();
+    sudokus.sort();
+
+    let mut duplicates = vec![];
+    for (i, sudokus) in sudokus.windows(2).enumerate() {
+        if let [sudoku1, sudoku2] = sudokus {
+            assert_eq!(sudoku1.n_clues(), n_clues);
+            if sudoku1 == sudoku2 {
+                duplicates.push(i);
+            }
+        } else {
+            unreachable!();
+        }
+    }
+
+    if !duplicates.is_empty() {
+        for i in duplicates {
+            println!("sudoku nr {} and next: {}", i, sudokus[i].to_str_line());
+        }
+
+        panic!("\nRandomly shuffled a sudoku into the above equal sudoku(s). This is possible, but very unlikely. Please save the sudoku(s) for debugging.");
+    }
+}
+
+#[test]
+fn parse_permissive() {
+    let sudokus = [
+        // http://norvig.com/sudoku.html
+        r"
+        4 . . |. . . |8 . 5
+        . 3 . |. . . |. . .
+        . . . |7 . . |. . .
+        ------+------+------
+        . 2 . |. . . |. 6 .
+        . . . |. 8 . |4 . .
+        . . . |. 1 . |. . .
+        ------+------+------
+        . . . |6 . 3 |. 7 .
+        5 . . |2 . . |. . .
+        1 . 4 |. . . |. . .
+        ",
+        // https://projecteuler.net/problem=96
+        r"
+        Grid 01
+        003020600
+        900305001
+        001806400
+        008102900
+        700000008
+        006708200
+        002609500
+        800203009
+        005010300
+        Grid 02
+        200080300
+        060070084
+        030500209
+        000105408
+        000000000
+        402706000
+        301007040
+        720040060
+        004010003
+        ",
+    ];
+    let sudokus_line = [
+        "4.....8.5.3..........7......2.....6.....8.4......1.......6.3.7.5..2.....1.4......",
+        "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..",
+    ];
+
+    for (sudoku, line_sudoku) in sudokus.iter().zip(sudokus_line.iter()) {
+        let sudoku1 = Sudoku::from_str_block_permissive(sudoku).expect("permissive block parse error");
+        let sudoku2 = Sudoku::from_str_line(line_sudoku).expect("line parse error");
+        assert!(sudoku1 == sudoku2);
+    }
+}
+
+#[test]
+fn canonicalize() {
+    let sudoku = Sudoku::generate_solved();
+    let (canonical, _) = sudoku.canonicalized().unwrap();
+
+    for i in 0..1_000 {
+        let (recanonicalized, _) = sudoku.shuffled().canonicalized().unwrap();
+        if recanonicalized != canonical {
+            panic!("canonicalize(shuffle(canonical_grid)) != canonical_grid on attempt nr {}\nbefore {}\nafter  {}", i, canonical.to_str_line(), recanonicalized.to_str_line());
+        }
+    }
+}
+
+#[test]
+fn canonicalize_idempotency() {
+    for _ in 0..1_000 {
+        let (sudoku, _) = Sudoku::generate_solved().canonicalized().unwrap();
+        let (recanonicalized, _) = sudoku.canonicalized().unwrap();
+        if sudoku != recanonicalized {
+            panic!(
+                "canonicalize(canonicalize(sudoku)) != canonicalize(sudoku)\n1. {}\n2. {}",
+                recanonicalized.to_str_line(),
+                sudoku.to_str_line()
+            );
+        }
+    }
+}
+
+#[allow(unused)]
+// compile time check to see if SudokuLine is nameable, constructable and printable
+fn print_line() {
+    let sudoku = Sudoku::from_bytes([0; 81]).unwrap();
+    let line: sudoku::board::SudokuLine = sudoku.to_str_line();
+    let dereffed_line: &str = &line;
+    println!("{}", line);
+}
+
+#[test]
+fn parse_permissive_custom_empty_cells() {
+    let sudoku = "
+    * * 3 | * 2 * | 6 * *
+    9 * * | 3 * 5 | * * 1
+    * * 1 | 8 * 6 | 4 * *
+    ------+-------+------
+    * * 8 | 1 * 2 | 9 * *
+    7 * * | * * * | * * 8
+    * * 6 | 7 * 8 | 2 * *
+    ------+-------+------
+    * * 2 | 6 * 9 | 5 * *
+    8 * * | 2 * 3 | * * 9
+    * * 5 | * 1 * | 3 * *
+    ";
+    let line_sudoku = "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..";
+
+    let sudoku1 = Sudoku::from_str_block_permissive_with(sudoku, |ch| ch == '*').unwrap();
+    let sudoku2 = Sudoku::from_str_line(line_sudoku).unwrap();
+    assert_eq!(sudoku1, sudoku2);
+
+    // without the custom predicate, '*' is ignored and no row is complete
+    assert!(Sudoku::from_str_block_permissive(sudoku).is_err());
+
+    // spaces as empty cells, indented or not
+    let spaces =
+        "  3 2 6  \n9  3 5  1\n  18 64  \n  81 29  \n7       8\n  67 82  \n  26 95  \n8  2 3  9\n  5 1 3  ";
+    let indented: String = spaces.lines().map(|line| format!("    {}\n", line)).collect();
+    for block in &[spaces, &indented] {
+        let sudoku = Sudoku::from_str_block_permissive_with(block, |ch| ch == ' ').unwrap();
+        assert_eq!(sudoku, sudoku2);
+    }
+}
+
+#[test]
+fn solve_with_constraints() {
+    const ALL: u16 = 0b1_1111_1111;
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = sudoku.solution().unwrap();
+    assert_eq!(sudoku.solve_with_constraints(&[ALL; 81]), Some(solution));
+
+    // the only solution is excluded
+    let mut allowed = [ALL; 81];
+    allowed[0] = ALL & !(1 << (solution.to_bytes()[0] - 1));
+    assert_eq!(sudoku.solve_with_constraints(&allowed), None);
+
+    // a clue that isn't allowed
+    let mut allowed = [ALL; 81];
+    allowed[3] = ALL & !(1 << 1);
+    assert_eq!(sudoku.solve_with_constraints(&allowed), None);
+
+    // the first row of an empty grid must be 9..=1
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    let mut allowed = [ALL; 81];
+    for (cell, mask) in allowed.iter_mut().take(9).enumerate() {
+        *mask = 1 << (8 - cell);
+    }
+    let solution = empty.solve_with_constraints(&allowed).unwrap();
+    assert!(solution.is_solved());
+    assert_eq!(solution.to_bytes()[..9], [9, 8, 7, 6, 5, 4, 3, 2, 1]);
+
+    assert_eq!(empty.solve_with_constraints(&[0; 81]), None);
+}
+
+#[test]
+fn smallest_solution() {
+    let sudoku = Sudoku::from_str_line(
+        "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..",
+    )
+    .unwrap();
+    let solution = sudoku.solution().unwrap();
+    assert_eq!(sudoku.smallest_solution(), Some(solution));
+
+    // blank out the first few rows to get many solutions
+    let mut bytes = solution.to_bytes();
+    for (cell, byte) in bytes.iter_mut().enumerate() {
+        if cell < 27 && cell % 4 != 0 {
+            *byte = 0;
+        }
+    }
+    let sudoku = Sudoku::from_bytes(bytes).unwrap();
+    let solutions = sudoku.solutions_up_to(100_000);
+    assert!(solutions.len() > 1 && solutions.len() < 100_000);
+    assert_eq!(sudoku.smallest_solution(), solutions.iter().min().cloned());
+
+    assert_eq!(
+        Sudoku::from_bytes([0; 81]).unwrap().smallest_solution(),
+        Sudoku::from_str_line(
+            "123456789456789123789123456214365897365897214897214365531642978642978531978531642",
+        )
+        .ok()
+    );
+
+    let mut impossible = [0; 81];
+    impossible[0] = 1;
+    impossible[1] = 1;
+    assert_eq!(Sudoku::from_bytes(impossible).unwrap().smallest_solution(), None);
+}
+
+#[test]
+fn smallest_solutions() {
+    let solution = Sudoku::from_str_line(
+        "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..",
+    )
+    .unwrap()
+    .solution()
+    .unwrap();
+    // blank out the first band to get many solutions
+    let mut bytes = solution.to_bytes();
+    bytes[..27].copy_from_slice(&[0; 27]);
+    let sudoku = Sudoku::from_bytes(bytes).unwrap();
+    let mut all_solutions = sudoku.solutions_up_to(100_000);
+    all_solutions.sort();
+    assert!(all_solutions.len() > 10);
+
+    assert_eq!(sudoku.smallest_solutions(10), &all_solutions[..10]);
+    assert_eq!(sudoku.smallest_solutions(100_000), all_solutions);
+    assert_eq!(sudoku.smallest_solutions(0), vec![]);
+
+    let mut impossible = [0; 81];
+    impossible[0] = 1;
+    impossible[1] = 1;
+    assert_eq!(
+        Sudoku::from_bytes(impossible).unwrap().smallest_solutions(5),
+        vec![]
+    );
+}
+
+#[test]
+fn solve_file() {
+    let input = "\
+..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..
+11...............................................................................
+..3.2.6..9..3.5
+";
+    let mut output = vec![];
+    sudoku::batch::solve_file(input.as_bytes(), &mut output, 2).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+
+    let solution = Sudoku::from_str_line(input.lines().next().unwrap())
+        .unwrap()
+        .solution()
+        .unwrap();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], &*solution.to_str_line());
+    assert_eq!(lines[1], "unsolvable");
+    assert!(lines[2].starts_with("error: "));
+}
+
+#[test]
+fn overlay() {
+    let solution = Sudoku::generate_solved();
+    let bytes = solution.to_bytes();
+    let mut top = [0; 81];
+    let mut bottom = [0; 81];
+    top[..45].copy_from_slice(&bytes[..45]);
+    bottom[36..].copy_from_slice(&bytes[36..]);
+
+    let top = Sudoku::from_bytes(top).unwrap();
+    let bottom = Sudoku::from_bytes(bottom).unwrap();
+    assert_eq!(top.overlay(&bottom).unwrap(), solution);
+    assert_eq!(bottom.overlay(&top).unwrap(), solution);
+
+    let mut conflicting = [0; 81];
+    conflicting[40] = bytes[40] % 9 + 1;
+    let conflicting = Sudoku::from_bytes(conflicting).unwrap();
+    let err = top.overlay(&conflicting).unwrap_err();
+    assert_eq!(err.cell, 40);
+    assert_eq!(err.digit, bytes[40]);
+    assert_eq!(err.other_digit, bytes[40] % 9 + 1);
+}
+
+#[test]
+fn fill_in() {
+    let puzzle = Sudoku::generate();
+    let solution = puzzle.solution().unwrap();
+
+    let mut sudoku = puzzle;
+    sudoku.fill_in(&solution).unwrap();
+    assert_eq!(sudoku, solution);
+
+    let mut wrong_solution = solution.to_bytes();
+    let cell = puzzle.as_bytes().iter().position(|&num| num != 0).unwrap();
+    wrong_solution[cell] = wrong_solution[cell] % 9 + 1;
+    let wrong_solution = Sudoku::from_bytes(wrong_solution).unwrap();
+
+    let mut sudoku = puzzle;
+    let error = sudoku.fill_in(&wrong_solution).unwrap_err();
+    assert_eq!(error.cell as usize, cell);
+    assert_eq!(sudoku, puzzle);
+}
+
+#[test]
+fn remove_clues() {
+    let solved = Sudoku::generate_solved();
+    let mut sudoku = solved;
+    sudoku.remove_clues(&[]);
+    assert_eq!(sudoku, solved);
+
+    sudoku.remove_clues(&[0, 9, 9, 80]);
+    assert_eq!(sudoku.n_empty(), 3);
+    assert_eq!(sudoku.iter().nth(9), Some(None));
+    assert_eq!(sudoku.overlay(&solved), Ok(solved));
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn remove_clues_out_of_range() {
+    Sudoku::generate_solved().remove_clues(&[0, 81]);
+}
+
+#[test]
+fn try_place() {
+    use sudoku::errors::PlacementError;
+
+    let puzzle = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = puzzle.solution().unwrap();
+
+    let mut sudoku = puzzle;
+    assert_eq!(sudoku.try_place(0, 0), Err(PlacementError::InvalidDigit(0)));
+    assert_eq!(sudoku.try_place(0, 10), Err(PlacementError::InvalidDigit(10)));
+    assert_eq!(
+        sudoku.try_place(3, 2),
+        Err(PlacementError::CellFilled { cell: 3, digit: 2 })
+    );
+    // 2 and 3 in the same row, 5 in the same column
+    assert_eq!(
+        sudoku.try_place(0, 2),
+        Err(PlacementError::Conflict { digit: 2, peer: 3 })
+    );
+    assert_eq!(
+        sudoku.try_place(0, 3),
+        Err(PlacementError::Conflict { digit: 3, peer: 8 })
+    );
+    assert_eq!(
+        sudoku.try_place(0, 5),
+        Err(PlacementError::Conflict { digit: 5, peer: 63 })
+    );
+    assert_eq!(sudoku, puzzle);
+
+    for (cell, &num) in puzzle.as_bytes().iter().enumerate() {
+        if num == 0 {
+            sudoku.try_place(cell as u8, solution.as_bytes()[cell]).unwrap();
+        }
+    }
+    assert_eq!(sudoku, solution);
+}
+
+#[test]
+fn retain_proper() {
+    let sudoku = Sudoku::generate();
+    let other = Sudoku::generate();
+    let mut non_unique = sudoku.to_bytes();
+    for byte in non_unique.iter_mut().take(40) {
+        *byte = 0;
+    }
+    let non_unique = Sudoku::from_bytes(non_unique).unwrap();
+
+    let puzzles = vec![sudoku, non_unique, sudoku.shuffled(), other, sudoku];
+    let proper = sudoku::batch::retain_proper(puzzles);
+    let is_equivalent = sudoku.canonicalized() == other.canonicalized();
+    match is_equivalent {
+        true => assert_eq!(proper, vec![sudoku]),
+        false => assert_eq!(proper, vec![sudoku, other]),
+    }
+}
+
+#[test]
+fn classify_batch() {
+    use sudoku::batch::{classify_batch, BatchStats};
+
+    let unique = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    let mut conflicting = [0; 81];
+    conflicting[0] = 1;
+    conflicting[1] = 1;
+    let conflicting = Sudoku::from_bytes(conflicting).unwrap();
+
+    let stats = classify_batch(&[unique, empty, conflicting, unique]);
+    assert_eq!(
+        stats,
+        BatchStats {
+            n_unsolvable: 1,
+            n_unique: 2,
+            n_multiple: 1,
+            average_clues: (2.0 * unique.n_clues() as f64 + 2.0) / 4.0,
+        }
+    );
+    assert_eq!(classify_batch(&[]), BatchStats::default());
+}
+
+#[test]
+fn verify_complete_batch() {
+    use sudoku::batch::verify_complete_batch;
+
+    let mut sudokus = vec![];
+    for _ in 0..10 {
+        let solved = Sudoku::generate_solved();
+        let mut empty_cell = solved.to_bytes();
+        empty_cell[40] = 0;
+        let mut conflict = solved.to_bytes();
+        conflict[1] = conflict[0];
+        sudokus.push(solved);
+        sudokus.push(Sudoku::from_bytes(empty_cell).unwrap());
+        sudokus.push(Sudoku::from_bytes(conflict).unwrap());
+    }
+    sudokus.push(Sudoku::from_bytes([0; 81]).unwrap());
+
+    let expected: Vec<bool> = sudokus.iter().map(Sudoku::is_solved).collect();
+    assert_eq!(expected.iter().filter(|&&is_solved| is_solved).count(), 10);
+    assert_eq!(verify_complete_batch(&sudokus), expected);
+    assert!(verify_complete_batch(&[]).is_empty());
+}
+
+#[test]
+fn counted_lines() {
+    use sudoku::batch::from_counted_lines;
+    use sudoku::errors::{CountedLinesError, LineParseError};
+
+    let lines = include_str!("../sudokus/Lines/easy_sudokus.txt");
+    let sudokus = read_sudokus(lines);
+    assert_eq!(from_counted_lines(lines).unwrap(), sudokus);
+
+    let counted = format!("{}\n{}\n", sudokus.len(), lines.trim_end());
+    assert_eq!(from_counted_lines(&counted).unwrap(), sudokus);
+
+    let counted = format!("{}\n{}", sudokus.len() + 1, lines);
+    assert_eq!(
+        from_counted_lines(&counted),
+        Err(CountedLinesError::CountMismatch {
+            expected: sudokus.len() + 1,
+            found: sudokus.len(),
+        })
+    );
+
+    let invalid = format!("2\n\n{}\n123\n", sudokus[0]);
+    assert_eq!(
+        from_counted_lines(&invalid),
+        Err(CountedLinesError::InvalidLine {
+            line: 4,
+            error: LineParseError::NotEnoughCells(3),
+        })
+    );
+    assert_eq!(from_counted_lines("0\n"), Ok(vec![]));
+    assert_eq!(from_counted_lines(""), Ok(vec![]));
+
+    // sudoku lines consisting of digits only are not mistaken for a count
+    let empty = "0".repeat(81);
+    assert_eq!(
+        from_counted_lines(&empty),
+        Ok(vec![Sudoku::from_bytes([0; 81]).unwrap()])
+    );
+    let digits = format!("{}123", "0".repeat(78));
+    let mut bytes = [0; 81];
+    bytes[78..].copy_from_slice(&[1, 2, 3]);
+    assert_eq!(
+        from_counted_lines(&digits),
+        Ok(vec![Sudoku::from_bytes(bytes).unwrap()])
+    );
+}
+
+#[test]
+fn solve_batch_with_progress() {
+    use sudoku::batch::solve_batch_with_progress;
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt"));
+    let solved = read_sudokus(include_str!("../sudokus/Lines/solved_easy_sudokus.txt"));
+    let mut progress = vec![];
+    let solutions = solve_batch_with_progress(&sudokus, 2, |done, total| progress.push((done, total)));
+
+    assert_eq!(solutions.len(), sudokus.len());
+    for (solutions, &solution) in solutions.iter().zip(&solved) {
+        assert_eq!(solutions, &[solution]);
+    }
+    assert_eq!(progress.last(), Some(&(sudokus.len(), sudokus.len())));
+    assert!(progress.windows(2).all(|window| window[0].0 < window[1].0));
+
+    let mut n_calls = 0;
+    assert!(solve_batch_with_progress(&[], 1, |_, _| n_calls += 1).is_empty());
+    assert_eq!(n_calls, 0);
+}
+
+#[test]
+fn opensudoku() {
+    use sudoku::batch::{from_opensudoku, to_opensudoku};
+    use sudoku::errors::{LineParseError, OpenSudokuError};
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt"));
+    let xml = to_opensudoku(&sudokus);
+    assert!(xml.contains("<game data=\"0"));
+    let read: Result<Vec<_>, _> = from_opensudoku(&xml).into_iter().collect();
+    assert_eq!(read.unwrap(), sudokus);
+
+    let xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<opensudoku>
+  <name>Test</name>
+  <gamebook />
+  <game level='1' data='{}'/>
+  <game />
+  <game data="123" />
+  <game
+    data="{}"></game>
+</opensudoku>"#,
+        sudokus[0].to_str_line(),
+        sudokus[1].to_str_line(),
+    );
+    assert_eq!(
+        from_opensudoku(&xml),
+        vec![
+            Ok(sudokus[0]),
+            Err(OpenSudokuError::MissingData),
+            Err(OpenSudokuError::InvalidData(LineParseError::NotEnoughCells(3))),
+            Ok(sudokus[1]),
+        ]
+    );
+}
+
+#[test]
+fn fingerprint() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use sudoku::board::Transform;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let fingerprint = sudoku.fingerprint();
+    for _ in 0..100 {
+        let transform = Transform::random(&mut rng);
+        assert_eq!(sudoku.with_transform(&transform).fingerprint(), fingerprint);
+    }
+    assert_eq!(
+        sudoku
+            .with_transform(&Transform::identity().transpose())
+            .fingerprint(),
+        fingerprint
+    );
+
+    // non-equivalent sudokus are distinguished at least sometimes
+    let other = sudoku.solution().unwrap();
+    assert_ne!(other.fingerprint(), fingerprint);
+    let mut fewer_clues = sudoku.to_bytes();
+    fewer_clues[3] = 0;
+    assert_ne!(
+        Sudoku::from_bytes(fewer_clues).unwrap().fingerprint(),
+        fingerprint
+    );
+}
+
+#[test]
+fn canonical_line() {
+    use std::collections::HashSet;
+    use sudoku::board::SudokuLine;
+
+    let sudoku = Sudoku::generate();
+    let (canonical, _) = sudoku.canonicalized().unwrap();
+    assert_eq!(sudoku.canonical_line(), Some(canonical.to_str_line()));
+
+    let lines: HashSet<SudokuLine> = (0..10)
+        .map(|_| sudoku.shuffled().canonical_line().unwrap())
+        .collect();
+    assert_eq!(lines.len(), 1);
+
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(empty.canonical_line(), None);
+}
+
+#[test]
+fn sudoku_line_try_from() {
+    use std::convert::TryFrom;
+    use sudoku::board::SudokuLine;
+    use sudoku::errors::LineParseError;
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let line = sudoku.to_line_string();
+    assert_eq!(SudokuLine::try_from(line.as_str()), Ok(sudoku.to_str_line()));
+    assert_eq!(sudoku.to_str_line().as_str(), line);
+
+    match SudokuLine::try_from(line.replacen('.', "0", 1).as_str()) {
+        Err(LineParseError::InvalidEntry(entry)) => assert_eq!((entry.cell, entry.ch), (0, '0')),
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(
+        SudokuLine::try_from(&line[..80]),
+        Err(LineParseError::NotEnoughCells(80))
+    );
+    assert_eq!(
+        SudokuLine::try_from(format!("{} ", line).as_str()),
+        Err(LineParseError::TooManyCells)
+    );
+}
+
+#[test]
+fn sudoku_line_hash_and_ord() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(value: impl Hash) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt"));
+    for pair in sudokus.windows(2) {
+        let (line, other_line) = (pair[0].to_str_line(), pair[1].to_str_line());
+        assert_eq!(hash(line), hash(&*line));
+        assert_eq!(line.cmp(&other_line), (*line).cmp(&*other_line));
+        assert_eq!(line.cmp(&other_line), pair[0].cmp(&pair[1]));
+    }
+}
+
+#[test]
+fn digit_set_bits() {
+    use sudoku::bitset::Set;
+    use sudoku::board::Digit;
+
+    let set = Digit::new(1).as_set() | Digit::new(9).as_set();
+    assert_eq!(set.bits(), 0b1_0000_0001);
+    assert_eq!(Set::<Digit>::try_from_bits(set.bits()), Some(set));
+    assert_eq!(Set::<Digit>::try_from_bits(0b11_1111_1111), None);
+    assert_eq!(Set::<Digit>::try_from_bits(0), Some(Set::NONE));
+    assert_eq!(Set::<Digit>::ALL.bits(), 0x1FF);
+}
+
+#[test]
+fn solve_until_guess() {
+    use sudoku::bitset::Set;
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt"));
+    let solved_sudokus = read_sudokus(include_str!("../sudokus/Lines/solved_hard_sudokus.txt"));
+    let mut n_guesses_needed = 0;
+    for (sudoku, solution) in sudokus.into_iter().zip(solved_sudokus) {
+        let (grid, candidates) = sudoku.solve_until_guess().unwrap();
+        if !grid.is_solved() {
+            n_guesses_needed += 1;
+        }
+        for ((&num, &solution_num), &cell_candidates) in grid
+            .as_bytes()
+            .iter()
+            .zip(solution.as_bytes())
+            .zip(candidates.iter())
+        {
+            match num {
+                0 => {
+                    assert!(cell_candidates.len() >= 2);
+                    assert!(cell_candidates.contains(sudoku::board::Digit::new(solution_num)));
+                }
+                _ => {
+                    assert_eq!(num, solution_num);
+                    assert_eq!(cell_candidates, Set::NONE);
+                }
+            }
+        }
+    }
+    assert!(n_guesses_needed > 0);
+
+    let mut conflicting = [0; 81];
+    conflicting[0] = 1;
+    conflicting[1] = 1;
+    let conflicting = Sudoku::from_bytes(conflicting).unwrap();
+    assert!(conflicting.solve_until_guess().is_none());
+}
+
+#[test]
+fn rating_report() {
+    use sudoku::strategy::Difficulty;
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt"));
+    for sudoku in sudokus {
+        let report = sudoku.rating_report().unwrap();
+        assert_eq!(Some(report.difficulty), Difficulty::of(sudoku));
+        let (_, tree_stats) = sudoku.solve_with_tree_stats();
+        assert_eq!(report.guesses + 1, tree_stats.total_nodes());
+        assert_eq!(
+            report.is_consistent(),
+            report.difficulty == Difficulty::Medium
+                || (report.difficulty == Difficulty::Easy) == (report.guesses == 0)
+        );
+    }
+
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(empty.rating_report(), None);
+}
+
+#[test]
+fn uniqueness_proof() {
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt"));
+    let mut n_refutations = 0;
+    for sudoku in sudokus {
+        let proof = sudoku.uniqueness_proof().unwrap();
+        assert_eq!(Some(proof.solution()), sudoku.solution());
+
+        for refutation in proof.refutations() {
+            let mut bytes = sudoku.to_bytes();
+            for guess in refutation.guesses() {
+                bytes[guess.cell.as_index()] = guess.digit.get();
+            }
+            let refuted = Sudoku::from_bytes(bytes).unwrap();
+            assert!(refuted.solve_until_guess().is_none(), "{}", refuted);
+            n_refutations += 1;
+        }
+    }
+    assert!(n_refutations > 0);
+
+    let easy = read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt"))[0];
+    assert!(easy.uniqueness_proof().unwrap().refutations().is_empty());
+
+    let ambiguous = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(ambiguous.uniqueness_proof(), None);
+    let mut conflicting = [0; 81];
+    conflicting[0] = 1;
+    conflicting[1] = 1;
+    assert_eq!(Sudoku::from_bytes(conflicting).unwrap().uniqueness_proof(), None);
+}
+
+#[test]
+fn solve_with_tree_stats() {
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt"));
+    let mut max_depth = 0;
+    for sudoku in sudokus {
+        let (solution, stats) = sudoku.solve_with_tree_stats();
+        assert_eq!(solution, sudoku.solution());
+        assert_eq!(stats.nodes_per_depth()[0], 1);
+        assert!(stats.nodes_per_depth().iter().all(|&nodes| nodes > 0));
+        assert_eq!(stats.branching_factors().len(), stats.max_depth());
+        max_depth = max_depth.max(stats.max_depth());
+    }
+    assert!(max_depth > 0);
+
+    let easy = read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt"))[0];
+    let (_, stats) = easy.solve_with_tree_stats();
+    assert_eq!(stats.nodes_per_depth(), &[1]);
+    assert_eq!(stats.total_nodes(), 1);
+
+    let mut conflicting = [0; 81];
+    conflicting[0] = 1;
+    conflicting[1] = 1;
+    let (solution, stats) = Sudoku::from_bytes(conflicting).unwrap().solve_with_tree_stats();
+    assert_eq!(solution, None);
+    assert_eq!(stats.max_depth(), 0);
+}
+
+#[test]
+fn solution_distribution() {
+    let solved = Sudoku::from_str_line(
+        "123456789456789123789123456214365897365897214897214365531642978642978531978531642",
+    )
+    .unwrap();
+    assert!(solved.is_solved());
+    // 1 and 2 can be swapped in this rectangle
+    let mut grid = solved.to_bytes();
+    for &cell in &[0, 1, 27, 28] {
+        grid[cell] = 0;
+    }
+    let sudoku = Sudoku::from_bytes(grid).unwrap();
+    assert_eq!(sudoku.solutions_count_up_to(100), 2);
+    assert_eq!(
+        sudoku.solution_distribution(0, 100),
+        [0, 1, 1, 0, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(sudoku.solution_distribution(0, 1), [0, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    // limited
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    let counts = empty.solution_distribution(40, 25);
+    assert_eq!(counts.iter().sum::<usize>(), 25);
+
+    // filled cell
+    let counts = solved.solution_distribution(0, 10);
+    assert_eq!(counts, [0, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    let unsolvable = read_sudokus(include_str!("../sudokus/Lines/invalid_sudokus.txt"))[0];
+    assert_eq!(unsolvable.solution_distribution(0, 10), [0; 10]);
+}
+
+#[test]
+fn solutions_with_budget() {
+    use sudoku::errors::Timeout;
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt"));
+    for sudoku in sudokus {
+        let solutions = sudoku.solutions_up_to(2);
+        assert_eq!(sudoku.solutions_up_to_with_budget(2, u64::MAX), Ok(solutions));
+    }
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(
+        empty.solutions_up_to_with_budget(100, 0),
+        Err(Timeout { max_nodes: 0 })
+    );
+    assert_eq!(
+        empty.solutions_up_to_with_budget(100, 50),
+        Err(Timeout { max_nodes: 50 })
+    );
+
+    let mut conflicting = [0; 81];
+    conflicting[0] = 1;
+    conflicting[1] = 1;
+    let conflicting = Sudoku::from_bytes(conflicting).unwrap();
+    assert_eq!(conflicting.solutions_up_to_with_budget(1, 0), Ok(vec![]));
+}
+
+#[test]
+fn most_constrained_cell() {
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    assert_eq!(empty.most_constrained_cell(), Some((0, 9)));
+
+    let mut grid = [0; 81];
+    grid[1] = 1;
+    grid[9] = 2;
+    grid[40] = 3;
+    let sudoku = Sudoku::from_bytes(grid).unwrap();
+    assert_eq!(sudoku.most_constrained_cell(), Some((0, 7)));
+
+    // cell 0 has no candidates left
+    let mut grid = [0; 81];
+    grid[1..9].copy_from_slice(&[2, 3, 4, 5, 6, 7, 8, 9]);
+    grid[9] = 1;
+    assert_eq!(
+        Sudoku::from_bytes(grid).unwrap().most_constrained_cell(),
+        Some((0, 0))
+    );
+
+    assert_eq!(Sudoku::generate_solved().most_constrained_cell(), None);
+}
+
+#[test]
+fn has_deadly_pattern_risk() {
+    let solved = Sudoku::from_str_line(
+        "123456789456789123789123456214365897365897214897214365531642978642978531978531642",
+    )
+    .unwrap();
+    assert!(!solved.has_deadly_pattern_risk());
+
+    // 1 and 2 can be swapped in a rectangle across 2 blocks
+    let mut grid = solved.to_bytes();
+    for &cell in &[0, 1, 27, 28] {
+        grid[cell] = 0;
+    }
+    let ambiguous = Sudoku::from_bytes(grid).unwrap();
+    assert!(ambiguous.has_deadly_pattern_risk());
+    assert!(!ambiguous.is_uniquely_solvable());
+
+    // a rectangle within a single block
+    let mut grid = solved.to_bytes();
+    for &cell in &[0, 2, 18, 20] {
+        grid[cell] = 0;
+    }
+    assert!(!Sudoku::from_bytes(grid).unwrap().has_deadly_pattern_risk());
+}
+
+#[test]
+fn candidates_after_placement() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = sudoku.solution().unwrap().to_bytes();
+
+    for cell in (0..81).filter(|&cell| sudoku.to_bytes()[cell as usize] == 0) {
+        let num = solution[cell as usize];
+        let candidates = sudoku.candidates_after_placement(cell, num).unwrap();
+
+        let mut bytes = sudoku.to_bytes();
+        bytes[cell as usize] = num;
+        let placed = Sudoku::from_bytes(bytes).unwrap();
+        for (&mask, digits) in candidates.iter().zip(placed.candidate_lists()) {
+            let expected = digits.iter().fold(0, |mask, &digit| mask | 1 << (digit - 1));
+            assert_eq!(mask, expected);
+        }
+    }
+
+    // conflicts with a clue in the same row
+    assert_eq!(sudoku.candidates_after_placement(0, 2), None);
+    // overwriting a clue
+    assert_eq!(sudoku.candidates_after_placement(3, 1), None);
+    assert!(sudoku.candidates_after_placement(3, 2).is_some());
+}
+
+#[test]
+fn completing_clues() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = sudoku.solution().unwrap();
+    // remove the first clue that is necessary for uniqueness
+    let ambiguous = (0..81)
+        .filter(|&cell| sudoku.to_bytes()[cell] != 0)
+        .map(|cell| {
+            let mut bytes = sudoku.to_bytes();
+            bytes[cell] = 0;
+            Sudoku::from_bytes(bytes).unwrap()
+        })
+        .find(|sudoku| !sudoku.is_uniquely_solvable())
+        .unwrap();
+
+    let clues = ambiguous.completing_clues(usize::MAX);
+    assert!(!clues.is_empty());
+    for &(cell, digit) in &clues {
+        let mut bytes = ambiguous.to_bytes();
+        assert_eq!(bytes[cell as usize], 0);
+        bytes[cell as usize] = digit;
+        assert!(Sudoku::from_bytes(bytes).unwrap().is_uniquely_solvable());
+    }
+    let mut sorted = clues.clone();
+    sorted.sort();
+    assert_eq!(sorted, clues);
+
+    assert_eq!(ambiguous.completing_clues(1), &clues[..1]);
+    assert!(ambiguous.completing_clues(0).is_empty());
+    assert!(sudoku.completing_clues(10).is_empty());
+    assert!(solution.completing_clues(10).is_empty());
+}
+
+#[test]
+fn diff_grid() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = sudoku.solution().unwrap();
+
+    let diff = sudoku.diff_grid(&solution, '|');
+    assert_eq!(diff.lines().count(), 11);
+    assert_eq!(diff.lines().next(), Some("|8||5||4|  2 |1||9| |7| 6  3 "));
+    assert_eq!(diff.matches('|').count(), 2 * sudoku.n_empty() as usize);
+
+    // unchanged grids are only padded
+    let same = sudoku.diff_grid(&sudoku, '|');
+    assert!(!same.contains('|'));
+    assert_eq!(
+        same.replace(' ', ""),
+        sudoku.display_block().to_string().replace(' ', "")[1..]
+    );
+}
+
+#[test]
+fn solve_session() {
+    use sudoku::solver::Step;
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt"));
+    for sudoku in sudokus.into_iter().take(20) {
+        let mut session = sudoku.solve_session();
+        let mut n_guesses = 0;
+        let mut n_backtracks = 0;
+        while let Some(step) = session.step() {
+            match step {
+                Step::Guess(_) => n_guesses += 1,
+                Step::Backtrack => n_backtracks += 1,
+                Step::NakedSingle(_) | Step::HiddenSingle(_) => {}
+            }
+            assert!(session.sudoku().conflicts().is_empty());
+        }
+        assert!(session.is_finished());
+        assert_eq!(session.solution(), sudoku.solution());
+        assert!(n_backtracks <= n_guesses);
+    }
+
+    // an easy sudoku needs no guesses
+    let sudoku = read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt"))[0];
+    let steps: Vec<_> = sudoku.solve_session().collect();
+    assert_eq!(steps.len(), sudoku.n_empty() as usize);
+    assert!(steps
+        .iter()
+        .all(|step| matches!(step, Step::NakedSingle(_) | Step::HiddenSingle(_))));
+
+    // a wrong entry that doesn't conflict with the clues
+    let solution = sudoku.solution().unwrap();
+    let candidates = sudoku.candidate_lists();
+    let (cell, &digit) = (0..81)
+        .find_map(|cell| {
+            let wrong = candidates[cell]
+                .iter()
+                .find(|&&digit| digit != solution.to_bytes()[cell])?;
+            Some((cell, wrong))
+        })
+        .unwrap();
+    let mut bytes = sudoku.to_bytes();
+    bytes[cell] = digit;
+    let mut session = Sudoku::from_bytes(bytes).unwrap().solve_session();
+    while session.step().is_some() {}
+    assert_eq!(session.solution(), None);
+
+    let mut conflicting = [0; 81];
+    conflicting[0] = 1;
+    conflicting[1] = 1;
+    let mut session = Sudoku::from_bytes(conflicting).unwrap().solve_session();
+    assert_eq!(session.step(), None);
+    assert_eq!(session.solution(), None);
+
+    let mut session = Sudoku::from_bytes([0; 81]).unwrap().solve_session();
+    while session.step().is_some() {}
+    assert!(session.solution().unwrap().is_solved());
+}
+
+#[test]
+fn for_each_solution() {
+    let few_clues = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3..................................",
+    )
+    .unwrap();
+    let solutions = few_clues.solutions_up_to(20);
+
+    let mut visited = vec![];
+    let n_solutions = few_clues.for_each_solution(20, |&solution| {
+        visited.push(solution);
+        true
+    });
+    assert_eq!(n_solutions, 20);
+    assert_eq!(visited, solutions);
+
+    // stop early
+    visited.clear();
+    let n_solutions = few_clues.for_each_solution(20, |&solution| {
+        visited.push(solution);
+        visited.len() < 5
+    });
+    assert_eq!(n_solutions, 5);
+    assert_eq!(visited, &solutions[..5]);
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    assert_eq!(sudoku.for_each_solution(10, |_| true), 1);
+    assert_eq!(sudoku.for_each_solution(0, |_| true), 0);
+}
+
+#[test]
+fn nth_solution() {
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    assert_eq!(sudoku.nth_solution(0), sudoku.solution());
+    assert_eq!(sudoku.nth_solution(1), None);
+
+    let few_clues = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3..................................",
+    )
+    .unwrap();
+    let solutions = few_clues.solutions_up_to(20);
+    assert_eq!(solutions.len(), 20);
+    for (n, &solution) in solutions.iter().enumerate() {
+        assert_eq!(few_clues.nth_solution(n), Some(solution));
+    }
+}
+
+#[test]
+fn has_empty_unit() {
+    use sudoku::board::positions::{Band, Block, Col, Row, Stack, Zone};
+
+    let solved = Sudoku::generate_solved();
+    let with_empty_cells = |cells: &mut dyn Iterator<Item = usize>| {
+        let mut bytes = solved.to_bytes();
+        for cell in cells {
+            bytes[cell] = 0;
+        }
+        Sudoku::from_bytes(bytes).unwrap()
+    };
+
+    assert_eq!(solved.has_empty_unit(), None);
+    assert_eq!(
+        Sudoku::from_bytes([0; 81]).unwrap().has_empty_unit(),
+        Some(Zone::Band(Band::new(0)))
+    );
+    let sudoku = with_empty_cells(&mut (0..81).filter(|cell| cell % 9 >= 6));
+    assert_eq!(sudoku.has_empty_unit(), Some(Zone::Stack(Stack::new(2))));
+    let sudoku = with_empty_cells(&mut (0..81).filter(|cell| cell / 27 == 2 && cell % 9 / 3 == 1));
+    assert_eq!(sudoku.has_empty_unit(), Some(Zone::Block(Block::new(7))));
+    let sudoku = with_empty_cells(&mut (36..45));
+    assert_eq!(sudoku.has_empty_unit(), Some(Zone::Row(Row::new(4))));
+    let sudoku = with_empty_cells(&mut (0..81).filter(|cell| cell % 9 == 8));
+    assert_eq!(sudoku.has_empty_unit(), Some(Zone::Col(Col::new(8))));
+
+    // every row, column and block keeps a clue
+    let sudoku = with_empty_cells(&mut (0..81).filter(|cell| cell % 9 != (cell / 9 * 3 + cell / 27) % 9));
+    assert_eq!(sudoku.has_empty_unit(), None);
+}
+
+#[test]
+fn pencil_marks() {
+    use sudoku::bitset::Set;
+    use sudoku::board::{Digit, PencilMarks};
+
+    let mut marks = PencilMarks::default();
+    assert!(marks.is_empty());
+    marks.insert(10, 3);
+    marks.insert(10, 3);
+    marks.toggle(10, 4);
+    marks.toggle(80, 9);
+    assert!(marks.contains(10, 3) && marks.contains(10, 4) && marks.contains(80, 9));
+    assert_eq!(marks.get(10).len(), 2);
+
+    marks.remove(10, 3);
+    marks.toggle(10, 4);
+    assert!(marks.get(10).is_empty());
+    marks.set(10, Set::ALL);
+    assert!((1..=9).all(|digit| marks.contains(10, digit)));
+    marks.clear(10);
+    assert!(marks.get(10).is_empty());
+
+    let bits = marks.to_bits();
+    assert_eq!(bits[80], 1 << 8);
+    assert_eq!(PencilMarks::from_bits(&bits), Some(marks));
+    let mut invalid = bits;
+    invalid[0] = 1 << 9;
+    assert_eq!(PencilMarks::from_bits(&invalid), None);
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let marks = PencilMarks::from_candidates(&sudoku);
+    for (cell, digits) in (0..).zip(sudoku.candidate_lists()) {
+        let expected = digits
+            .iter()
+            .fold(Set::NONE, |set, &digit| set | Digit::new(digit));
+        assert_eq!(marks.get(cell), expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn pencil_marks_invalid_digit() {
+    sudoku::board::PencilMarks::new().toggle(0, 10);
+}
+
+#[test]
+fn bytes_zero_based() {
+    use sudoku::errors::FromZeroBasedError;
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt"));
+    for sudoku in sudokus {
+        for &marker in &[9, 42, 255] {
+            let bytes = sudoku.to_bytes_zero_based(marker);
+            assert!(bytes.iter().all(|&byte| byte < 9 || byte == marker));
+            assert_eq!(Sudoku::from_bytes_zero_based(bytes, marker), Ok(sudoku));
+        }
+    }
+
+    let mut bytes = [9; 81];
+    assert_eq!(
+        Sudoku::from_bytes_zero_based(bytes, 8),
+        Err(FromZeroBasedError::InvalidEmptyMarker(8))
+    );
+    bytes[5] = 10;
+    assert_eq!(
+        Sudoku::from_bytes_zero_based(bytes, 9),
+        Err(FromZeroBasedError::InvalidEntry { cell: 5, byte: 10 })
+    );
+}
+
+#[test]
+#[should_panic]
+fn to_bytes_zero_based_invalid_marker() {
+    Sudoku::from_bytes([0; 81]).unwrap().to_bytes_zero_based(0);
+}
+
+#[test]
+fn from_entries() {
+    use sudoku::errors::FromEntriesError;
+
+    let sudoku = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let bytes = sudoku.to_bytes();
+    let entries = (0..81)
+        .map(|cell| (cell, bytes[cell as usize]))
+        .filter(|&(_, digit)| digit != 0);
+    assert_eq!(Sudoku::from_entries(entries.clone()), Ok(sudoku));
+    // repeated entries are allowed
+    assert_eq!(
+        Sudoku::from_entries(entries.clone().rev().chain(entries)),
+        Ok(sudoku)
+    );
+    assert!(Sudoku::from_entries(vec![]).unwrap().is_empty());
+
+    assert_eq!(
+        Sudoku::from_entries(vec![(81, 1)]),
+        Err(FromEntriesError::InvalidCell(81))
+    );
+    assert_eq!(
+        Sudoku::from_entries(vec![(3, 0)]),
+        Err(FromEntriesError::InvalidDigit { cell: 3, digit: 0 })
+    );
+    assert_eq!(
+        Sudoku::from_entries(vec![(3, 10)]),
+        Err(FromEntriesError::InvalidDigit { cell: 3, digit: 10 })
+    );
+    assert_eq!(
+        Sudoku::from_entries(vec![(3, 1), (4, 1), (3, 2)]),
+        Err(FromEntriesError::ConflictingEntries {
+            cell: 3,
+            digit: 1,
+            other_digit: 2,
+        })
+    );
+}
+
+#[test]
+fn solutions_with_passes() {
+    use sudoku::solver::Passes;
+
+    let all_passes = [
+        Passes::NONE,
+        Passes::NAKED_SINGLES,
+        Passes::HIDDEN_SINGLES,
+        Passes::NAKED_SINGLES | Passes::HIDDEN_SINGLES,
+    ];
+    assert_eq!(Passes::default(), Passes::ALL);
+    assert_eq!(all_passes[3], Passes::ALL);
+    assert!(Passes::ALL.contains(Passes::NAKED_SINGLES));
+    assert!(Passes::ALL.contains(Passes::HIDDEN_SINGLES));
+    assert!(!Passes::NAKED_SINGLES.contains(Passes::HIDDEN_SINGLES));
+    assert!(!Passes::NONE.contains(Passes::NAKED_SINGLES));
+    assert_eq!(Passes::NONE | Passes::NAKED_SINGLES, Passes::NAKED_SINGLES);
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt"));
+    for sudoku in sudokus {
+        let solutions = sudoku.solutions_up_to(2);
+        for &passes in &all_passes {
+            assert_eq!(
+                sudoku.solutions_up_to_with_passes(2, passes),
+                solutions,
+                "{:?}",
+                passes
+            );
+        }
+    }
+
+    // all solutions are found, regardless of their order
+    let line = "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...";
+    let mut bytes = Sudoku::from_str_line(line).unwrap().to_bytes();
+    bytes[3..8].copy_from_slice(&[0; 5]);
+    let ambiguous = Sudoku::from_bytes(bytes).unwrap();
+    let mut solutions = ambiguous.solutions_up_to(1000);
+    solutions.sort();
+    assert_eq!(solutions.len(), 742);
+    for &passes in &all_passes {
+        let mut solutions_with_passes = ambiguous.solutions_up_to_with_passes(1000, passes);
+        solutions_with_passes.sort();
+        assert_eq!(solutions_with_passes, solutions, "{:?}", passes);
+    }
+
+    let empty = Sudoku::from_bytes([0; 81]).unwrap();
+    for &passes in &all_passes {
+        let solutions = empty.solutions_up_to_with_passes(50, passes);
+        assert_eq!(solutions.len(), 50);
+        assert!(solutions.iter().all(Sudoku::is_solved));
+        let mut distinct = solutions.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(distinct.len(), 50);
+    }
+
+    let mut unsolvable = [0; 81];
+    unsolvable[0] = 1;
+    unsolvable[1] = 1;
+    let unsolvable = Sudoku::from_bytes(unsolvable).unwrap();
+    for &passes in &all_passes {
+        assert!(unsolvable.solutions_up_to_with_passes(1, passes).is_empty());
+    }
+}
+
+#[test]
+fn solutions_with_guess_strategy() {
+    use sudoku::board::Cell;
+    use sudoku::solver::{DefaultGuessStrategy, GuessStrategy, SolverState};
+
+    // guess on the unsolved cell with the most candidates
+    struct MostCandidates;
+
+    impl GuessStrategy for MostCandidates {
+        fn select_cell(&mut self, state: &SolverState<'_>) -> Cell {
+            state
+                .unsolved_cells()
+                .max_by_key(|&cell| state.candidates(cell).len())
+                .unwrap()
+        }
+    }
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/hard_sudokus.txt"));
+    let mut bytes = sudokus[0].to_bytes();
+    bytes[..9].copy_from_slice(&[0; 9]);
+    let multiple_solutions = Sudoku::from_bytes(bytes).unwrap();
+
+    for &sudoku in sudokus.iter().take(10).chain(Some(&multiple_solutions)) {
+        let mut solutions = sudoku.solutions_up_to(1000);
+        solutions.sort();
+
+        let mut default_solutions =
+            sudoku.solutions_up_to_with_guess_strategy(1000, &mut DefaultGuessStrategy);
+        default_solutions.sort();
+        assert_eq!(solutions, default_solutions);
+
+        let mut custom_solutions = sudoku.solutions_up_to_with_guess_strategy(1000, &mut MostCandidates);
+        custom_solutions.sort();
+        assert_eq!(solutions, custom_solutions);
+    }
+}
+
+#[test]
+fn owned_strings() {
+    let sudoku = Sudoku::generate();
+    assert_eq!(sudoku.to_line_string(), &*sudoku.to_str_line());
+    assert_eq!(Sudoku::from_str_line(&sudoku.to_line_string()).unwrap(), sudoku);
+    assert_eq!(
+        Sudoku::from_str_block_permissive(&sudoku.to_block_string()).unwrap(),
+        sudoku
+    );
+}
+
+#[test]
+fn solutionless_sudokus_contradiction() {
+    use sudoku::board::positions::HouseType;
+    use sudoku::errors::Contradiction;
+
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/invalid_sudokus.txt"));
+    for sudoku in sudokus {
+        assert!(sudoku.some_solution_or_contradiction().is_err());
+    }
+
+    let sudoku = Sudoku::from_str_line(
+        "12345678........................................................................9",
+    )
+    .unwrap();
+    assert_eq!(
+        sudoku.some_solution_or_contradiction(),
+        Err(Contradiction::NoCandidates { cell: 8 })
+    );
+
+    // no place for a 1 in the first row and block
+    let sudoku = Sudoku::from_str_line(
+        "..2.........1...........1..1...........................1.........................",
+    )
+    .unwrap();
+    match sudoku.some_solution_or_contradiction() {
+        Err(Contradiction::MissingDigit { house, digit: 1 }) => {
+            assert!(matches!(house, HouseType::Row(_) | HouseType::Block(_)))
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    let solution = Sudoku::generate_solved();
+    assert_eq!(solution.some_solution_or_contradiction(), Ok(solution));
+}
+
+#[test]
+fn parse_box_drawing() {
+    use sudoku::errors::BlockParseError;
+
+    let sudoku = "
+╔═══╤═══╤═══╦═══╤═══╤═══╦═══╤═══╤═══╗
+║ . │ . │ 3 ║ . │ 2 │ . ║ 6 │ . │ . ║
+║ 9 │ . │ . ║ 3 │ . │ 5 ║ . │ . │ 1 ║
+║ . │ . │ 1 ║ 8 │ . │ 6 ║ 4 │ . │ . ║
+╠═══╪═══╪═══╬═══╪═══╪═══╬═══╪═══╪═══╣
+║ . │ . │ 8 ║ 1 │ . │ 2 ║ 9 │ . │ . ║
+║ 7 │ . │ . ║ . │ . │ . ║ . │ . │ 8 ║
+║ . │ . │ 6 ║ 7 │ . │ 8 ║ 2 │ . │ . ║
+╠═══╪═══╪═══╬═══╪═══╪═══╬═══╪═══╪═══╣
+║ . │ . │ 2 ║ 6 │ . │ 9 ║ 5 │ . │ . ║
+║ 8 │ . │ . ║ 2 │ . │ 3 ║ . │ . │ 9 ║
+║ . │ . │ 5 ║ . │ 1 │ . ║ 3 │ . │ . ║
+╚═══╧═══╧═══╩═══╧═══╧═══╩═══╧═══╧═══╝
+";
+    let line_sudoku = "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..";
+    assert_eq!(
+        Sudoku::from_str_box_drawing(sudoku).unwrap(),
+        Sudoku::from_str_line(line_sudoku).unwrap()
+    );
+
+    let garbage = sudoku.replacen('7', "x", 1);
+    match Sudoku::from_str_box_drawing(&garbage) {
+        Err(BlockParseError::InvalidEntry(entry)) => assert_eq!((entry.cell, entry.ch), (36, 'x')),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    let ascii = sudoku.replace('║', "|");
+    assert!(Sudoku::from_str_box_drawing(&ascii).is_err());
+}
+
+#[test]
+fn try_solve() {
+    use sudoku::errors::SolveError;
+
+    let puzzle = Sudoku::generate();
+    let mut sudoku = puzzle;
+    assert_eq!(sudoku.try_solve(), Ok(()));
+    assert_eq!(Some(sudoku), puzzle.solution());
+
+    let mut conflicting = [0; 81];
+    conflicting[0] = 1;
+    conflicting[1] = 1;
+    let conflicting = Sudoku::from_bytes(conflicting).unwrap();
+    let mut sudoku = conflicting;
+    assert_eq!(sudoku.try_solve(), Err(SolveError::NoSolution));
+    assert_eq!(sudoku, conflicting);
+}
+
+#[test]
+fn expect_solution() {
+    let sudokus = read_sudokus(include_str!("../sudokus/Lines/easy_sudokus.txt"));
+    for sudoku in sudokus {
+        assert_eq!(Some(sudoku.expect_solution()), sudoku.solution());
+    }
+}
+
+#[test]
+#[should_panic(expected = "multiple solutions")]
+fn expect_solution_multiple_solutions() {
+    Sudoku::from_bytes([0; 81]).unwrap().expect_solution();
+}
+
+#[test]
+#[should_panic(expected = "no solution")]
+fn expect_solution_no_solution() {
+    read_sudokus(include_str!("../sudokus/Lines/invalid_sudokus.txt"))[0].expect_solution();
+}
+
+#[test]
+fn from_str_line_letters() {
+    use sudoku::errors::LineParseError;
+
+    let digits = "..3.2.6..9..3.5..1..18.64....81.29..7.......8..67.82....26.95..8..2.3..9..5.1.3..";
+    let letters = "..C.B.F..I..C.E..A..AH.FD....HA.BI..G.......H..FG.HB....BF.IE..H..B.C..I..E.A.C..";
+    let sudoku = Sudoku::from_str_line(digits).unwrap();
+
+    assert_eq!(Sudoku::from_str_line_letters(letters).unwrap(), sudoku);
+    assert_eq!(
+        Sudoku::from_str_line_letters(&letters.to_lowercase()).unwrap(),
+        sudoku
+    );
+    assert_eq!(
+        Sudoku::from_str_line_letters(&letters.replace('.', "0")).unwrap(),
+        sudoku
+    );
+    assert_eq!(
+        Sudoku::from_str_line_letters(&format!("  {} comment", letters)).unwrap(),
+        sudoku
+    );
+
+    match Sudoku::from_str_line_letters(digits) {
+        Err(LineParseError::InvalidEntry(entry)) => assert_eq!((entry.cell, entry.ch), (2, '3')),
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(
+        Sudoku::from_str_line_letters(&letters[..80]),
+        Err(LineParseError::NotEnoughCells(80))
+    );
+    assert_eq!(
+        Sudoku::from_str_line_letters(&format!("{}A", letters)),
+        Err(LineParseError::TooManyCells)
+    );
+    assert_eq!(
+        Sudoku::from_str_line_letters(&format!("{}x", letters)),
+        Err(LineParseError::MissingCommentDelimiter)
+    );
+}
+
+#[test]
+fn puzzle() {
+    use sudoku::board::Puzzle;
+    use sudoku::errors::{EditError, PuzzleParseError};
+
+    let givens = Sudoku::from_str_line(
+        "...2...633....54.1..1..398........9....538....3........263..5..5.37....847...1...",
+    )
+    .unwrap();
+    let solution = givens.solution().unwrap();
+    let mut puzzle = Puzzle::new(givens);
+    assert_eq!(Puzzle::from_str_line(&givens.to_line_string()), Ok(puzzle));
+
+    assert_eq!(puzzle.set(3, 2), Err(EditError::Given { cell: 3, digit: 2 }));
+    assert_eq!(puzzle.clear(3), Err(EditError::Given { cell: 3, digit: 2 }));
+    assert_eq!(puzzle.set(0, 0), Err(EditError::InvalidDigit(0)));
+    assert!(puzzle.is_given(3) && !puzzle.is_given(0));
+
+    // conflicting entries are allowed
+    puzzle.set(0, 2).unwrap();
+    assert_eq!(puzzle.conflicts(), vec![0, 3]);
+    puzzle.clear(0).unwrap();
+    assert!(puzzle.conflicts().is_empty());
+    assert_eq!(puzzle.grid(), givens);
+
+    for cell in 0..81 {
+        if !puzzle.is_given(cell) {
+            puzzle.set(cell, solution.to_bytes()[cell as usize]).unwrap();
+        }
+    }
+    assert!(puzzle.is_solved());
+    assert_eq!(puzzle.givens(), givens);
+    assert_eq!(puzzle.givens().overlay(&puzzle.fills()), Ok(solution));
+    assert_eq!(puzzle.givens_mask(), givens.clues_mask());
+
+    let saved = puzzle.to_string();
+    assert_eq!(Puzzle::from_str_line(&saved), Ok(puzzle));
+    assert_eq!(Puzzle::from_parts(puzzle.givens(), puzzle.fills()), Ok(puzzle));
+    assert_eq!(
+        Puzzle::from_parts(givens, solution),
+        Err(EditError::Given { cell: 3, digit: 2 })
+    );
+    assert_eq!(
+        Puzzle::from_str_line(&format!("{} {}", givens, solution)),
+        Err(PuzzleParseError::FillOnGiven(3))
+    );
+    assert!(matches!(
+        Puzzle::from_str_line(&saved[1..]),
+        Err(PuzzleParseError::Givens(_))
+    ));
+    assert!(matches!(
+        Puzzle::from_str_line(&saved[..saved.len() - 1]),
+        Err(PuzzleParseError::Fills(_))
+    ));
 }